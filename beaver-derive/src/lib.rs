@@ -0,0 +1,340 @@
+//! A derive macro for [beaver](https://docs.rs/beaver) factories.
+//!
+//! You don't need to depend on this crate directly. Enable `derive` feature of `beaver` and
+//! use `#[derive(beaver::Factory)]`.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote, quote_spanned};
+use syn::meta::ParseNestedMeta;
+use syn::spanned::Spanned;
+use syn::{Attribute, Data, DeriveInput, Error, Fields, Ident, LitStr, Path, Visibility};
+
+/// Defines a factory of a struct like [define!](https://docs.rs/beaver/latest/beaver/macro.define.html).
+///
+/// The factory is named `[struct name]Factory`, or the value of `#[factory(name = "...")]`.
+/// If you put `#[factory(clone)]`, the factory clones the struct instead of serializing it.
+///
+/// Every field needs one of the following attributes.
+/// - `#[factory(seq = "post-{n}")]`: a `String` formatted with the sequence number `n`.
+/// - `#[factory(seq)]`: the sequence number converted to the type of the field.
+/// - `#[factory(default)]`: `Default::default()`.
+/// - `#[factory(with = path)]`: the value returned by the function `path`, which takes the sequence number.
+///
+/// # Usage
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+///
+/// fn approved(n: u64) -> bool {
+///     n > 1
+/// }
+///
+/// #[derive(Serialize, Deserialize, beaver::Factory)]
+/// #[factory(name = "PostFactory")]
+/// struct Post {
+///     #[factory(seq)]
+///     id: u32,
+///     #[factory(seq = "post-{n}")]
+///     title: String,
+///     #[factory(with = approved)]
+///     approved: bool,
+///     #[factory(default)]
+///     tags: Vec<String>,
+/// }
+///
+/// let post_factory = PostFactory::new();
+/// let post = post_factory.build(|_| {});
+/// assert_eq!(post.id, 1);
+/// assert_eq!(post.title, "post-1");
+/// assert!(!post.approved);
+/// assert!(post.tags.is_empty());
+/// ```
+#[proc_macro_derive(Factory, attributes(factory))]
+pub fn derive_factory(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// A generator of a field, which is passed to `define!` as `field -> generator`.
+enum Generator {
+    Seq(Option<LitStr>),
+    Default,
+    With(Path),
+}
+
+fn expand(input: DeriveInput) -> Result<TokenStream2, Error> {
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "`Factory` can't be derived for a generic struct",
+        ));
+    }
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            fields => {
+                return Err(Error::new_spanned(
+                    fields,
+                    "`Factory` can only be derived for a struct with named fields",
+                ))
+            }
+        },
+        Data::Enum(data) => {
+            return Err(Error::new_spanned(
+                data.enum_token,
+                "`Factory` can only be derived for a struct",
+            ))
+        }
+        Data::Union(data) => {
+            return Err(Error::new_spanned(
+                data.union_token,
+                "`Factory` can only be derived for a struct",
+            ))
+        }
+    };
+
+    let mut name = None;
+    let mut clone = false;
+    for attr in factory_attrs(&input.attrs) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("name") {
+                let value: LitStr = meta.value()?.parse()?;
+                name = Some(value.parse::<Ident>().map_err(|_| {
+                    Error::new(value.span(), "`name` must be an identifier of a factory")
+                })?);
+                Ok(())
+            } else if meta.path.is_ident("clone") {
+                clone = true;
+                Ok(())
+            } else {
+                Err(unknown_attribute(&meta, "`name` or `clone`"))
+            }
+        })?;
+    }
+
+    // Errors of all fields are reported at once.
+    let mut errors: Option<Error> = None;
+    let mut generators = vec![];
+    for field in fields {
+        let ident = field.ident.as_ref().expect("named fields have identifiers");
+        match field_generator(&field.attrs) {
+            Ok(Some(generator)) => generators.push(field_tokens(ident, generator)),
+            Ok(None) => combine(
+                &mut errors,
+                Error::new(
+                    ident.span(),
+                    format!(
+                        "field `{}` has no generator. Add `#[factory(seq = \"...\")]`, `#[factory(seq)]`, \
+                         `#[factory(default)]` or `#[factory(with = path)]`",
+                        ident
+                    ),
+                ),
+            ),
+            Err(err) => combine(&mut errors, err),
+        }
+    }
+    if let Some(errors) = errors {
+        return Err(errors);
+    }
+
+    let struct_name = &input.ident;
+    let name = name.unwrap_or_else(|| format_ident!("{}Factory", struct_name));
+    // `define!` only knows `pub`, so a factory of a restricted struct is private.
+    let vis = match &input.vis {
+        Visibility::Public(token) => quote!(#token),
+        _ => quote!(),
+    };
+    let clone = if clone { quote!(clone) } else { quote!() };
+    Ok(quote! {
+        ::beaver::define! {
+            #vis #name (#struct_name) #clone {
+                #(#generators,)*
+            }
+        }
+    })
+}
+
+fn field_generator(attrs: &[Attribute]) -> Result<Option<Generator>, Error> {
+    let mut generator = None;
+    for attr in factory_attrs(attrs) {
+        attr.parse_nested_meta(|meta| {
+            let value = if meta.path.is_ident("seq") {
+                if meta.input.peek(syn::Token![=]) {
+                    let format: LitStr = meta.value()?.parse()?;
+                    if !format.value().contains("{n") {
+                        return Err(Error::new(format.span(), "`seq` must contain `{n}`"));
+                    }
+                    Generator::Seq(Some(format))
+                } else {
+                    Generator::Seq(None)
+                }
+            } else if meta.path.is_ident("default") {
+                Generator::Default
+            } else if meta.path.is_ident("with") {
+                Generator::With(meta.value()?.parse()?)
+            } else {
+                return Err(unknown_attribute(&meta, "`seq`, `default` or `with`"));
+            };
+            if generator.is_some() {
+                return Err(meta.error("a field can only have one generator"));
+            }
+            generator = Some(value);
+            Ok(())
+        })?;
+    }
+    Ok(generator)
+}
+
+fn field_tokens(ident: &Ident, generator: Generator) -> TokenStream2 {
+    // Generators are spanned to their attributes, so type errors point at the attribute.
+    match generator {
+        Generator::Seq(Some(format)) => quote_spanned! {format.span()=>
+            #ident -> |n| ::std::convert::Into::into(::std::format!(#format, n = n))
+        },
+        Generator::Seq(None) => {
+            let message = format!("Sequence number `{{}}` overflowed the field `{}`.", ident);
+            // A generic function doesn't trigger `clippy::useless_conversion` for `u64` fields.
+            quote_spanned! {ident.span()=>
+                #ident -> |n| {
+                    fn convert<T: ::std::convert::TryFrom<u64>>(n: u64) -> T {
+                        T::try_from(n).unwrap_or_else(|_| ::std::panic!(#message, n))
+                    }
+                    convert(n)
+                }
+            }
+        }
+        Generator::Default => quote_spanned! {ident.span()=>
+            #ident -> |_| ::std::default::Default::default()
+        },
+        Generator::With(path) => quote_spanned! {path.span()=>
+            #ident -> |n| #path(n)
+        },
+    }
+}
+
+fn factory_attrs(attrs: &[Attribute]) -> impl Iterator<Item = &Attribute> {
+    attrs.iter().filter(|attr| attr.path().is_ident("factory"))
+}
+
+fn unknown_attribute(meta: &ParseNestedMeta, expected: &str) -> Error {
+    let path = &meta.path;
+    let path = quote!(#path).to_string().replace(' ', "");
+    meta.error(format!(
+        "unknown factory attribute `{}`. Expected {}",
+        path, expected
+    ))
+}
+
+fn combine(errors: &mut Option<Error>, err: Error) {
+    match errors {
+        Some(errors) => errors.combine(err),
+        None => *errors = Some(err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::expand;
+    use syn::{parse_quote, DeriveInput};
+
+    fn error(input: DeriveInput) -> String {
+        expand(input).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn test_expand() {
+        let tokens = expand(parse_quote! {
+            #[factory(name = "PostFactory")]
+            pub struct Post {
+                #[factory(seq)]
+                id: u64,
+                #[factory(seq = "post-{n}")]
+                title: String,
+            }
+        })
+        .unwrap()
+        .to_string();
+        assert!(tokens.starts_with(":: beaver :: define !"));
+        assert!(tokens.contains("pub PostFactory (Post)"));
+    }
+
+    #[test]
+    fn test_expand_with_default_name() {
+        let tokens = expand(parse_quote! {
+            #[factory(clone)]
+            struct Post {
+                #[factory(default)]
+                id: u64,
+            }
+        })
+        .unwrap()
+        .to_string();
+        assert!(tokens.contains("{ PostFactory (Post) clone {"));
+    }
+
+    #[test]
+    fn test_missing_generator() {
+        let message = error(parse_quote! {
+            struct Post {
+                #[factory(seq)]
+                id: u64,
+                title: String,
+            }
+        });
+        assert!(message.starts_with("field `title` has no generator."));
+    }
+
+    #[test]
+    fn test_invalid_attributes() {
+        let message = error(parse_quote! {
+            struct Post {
+                #[factory(seq, default)]
+                id: u64,
+            }
+        });
+        assert_eq!(message, "a field can only have one generator");
+
+        let message = error(parse_quote! {
+            struct Post {
+                #[factory(sequence)]
+                id: u64,
+            }
+        });
+        assert_eq!(
+            message,
+            "unknown factory attribute `sequence`. Expected `seq`, `default` or `with`"
+        );
+
+        let message = error(parse_quote! {
+            struct Post {
+                #[factory(seq = "post")]
+                title: String,
+            }
+        });
+        assert_eq!(message, "`seq` must contain `{n}`");
+
+        let message = error(parse_quote! {
+            #[factory(name = "post factory")]
+            struct Post {}
+        });
+        assert_eq!(message, "`name` must be an identifier of a factory");
+    }
+
+    #[test]
+    fn test_unsupported_items() {
+        let message = error(parse_quote! {
+            enum Post {}
+        });
+        assert_eq!(message, "`Factory` can only be derived for a struct");
+
+        let message = error(parse_quote! {
+            struct Post(u64);
+        });
+        assert_eq!(
+            message,
+            "`Factory` can only be derived for a struct with named fields"
+        );
+    }
+}
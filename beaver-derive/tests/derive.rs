@@ -0,0 +1,78 @@
+use serde::{Deserialize, Serialize};
+
+mod generators {
+    pub fn approved(n: u64) -> bool {
+        n > 1
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, beaver::Factory)]
+#[factory(name = "PostFactory")]
+pub struct Post {
+    #[factory(seq)]
+    id: u16,
+    #[factory(seq = "post-{n}")]
+    title: String,
+    #[factory(seq = "Post {n:03}")]
+    label: String,
+    #[factory(with = generators::approved)]
+    approved: bool,
+    #[factory(default)]
+    tags: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, beaver::Factory)]
+#[factory(clone)]
+struct Tag {
+    #[factory(seq)]
+    id: u64,
+    #[factory(seq = "tag-{n}")]
+    name: String,
+}
+
+#[test]
+fn is_builds_struct_from_derived_factory() {
+    let post_factory = PostFactory::new();
+    let posts = post_factory.build_list(2, |post| post.tags.push("beaver".to_string()));
+
+    assert_eq!(
+        posts,
+        vec![
+            Post {
+                id: 1,
+                title: "post-1".to_string(),
+                label: "Post 001".to_string(),
+                approved: false,
+                tags: vec!["beaver".to_string()],
+            },
+            Post {
+                id: 2,
+                title: "post-2".to_string(),
+                label: "Post 002".to_string(),
+                approved: true,
+                tags: vec!["beaver".to_string()],
+            },
+        ]
+    );
+    assert_eq!(PostFactory::build(10).title, "post-10");
+}
+
+#[test]
+fn is_builds_struct_from_derived_factory_with_default_name() {
+    let tag_factory = TagFactory::new();
+    let tag = tag_factory.build(|_| {});
+
+    assert_eq!(
+        tag,
+        Tag {
+            id: 1,
+            name: "tag-1".to_string(),
+        }
+    );
+}
+
+#[test]
+#[should_panic(expected = "Sequence number `70000` overflowed the field `id`.")]
+fn is_panics_with_overflowed_sequence() {
+    PostFactory::build(70_000);
+}
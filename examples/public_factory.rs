@@ -4,23 +4,22 @@ use serde::{Deserialize, Serialize};
 // `Post` needs to be public.
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Post {
-    id: u16,
+    id: u64,
     title: String,
     approved: bool,
     created_at: NaiveDateTime,
 }
 
 mod factory {
-    use crate::Post;
     use chrono::NaiveDate;
 
     beaver::define! {
-        // `PostFactory` needs to be public.
-        pub PostFactory (Post) {
+        // `PostFactory` needs to be public. The struct can be referred by its path.
+        pub PostFactory (crate::Post) {
             id -> |n| n,
             title -> |n| format!("post-{}", n),
             approved -> |_| false,
-            created_at -> |_| NaiveDate::from_ymd(2020, 1, 1).and_hms(0, 0, 0),
+            created_at -> |_| NaiveDate::from_ymd_opt(2020, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap(),
         }
     }
 }
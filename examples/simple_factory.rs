@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 // `Post` needs both of `Serialize` and `Deserialize`.
 #[derive(Serialize, Deserialize, Debug)]
 struct Post {
-    id: u16,
+    id: u64,
     title: String,
     approved: bool,
     created_at: NaiveDateTime,
@@ -15,7 +15,7 @@ beaver::define! {
         id -> |n| n,
         title -> |n| format!("post-{}", n),
         approved -> |_| false,
-        created_at -> |_| NaiveDate::from_ymd(2020, 1, 1).and_hms(0, 0, 0),
+        created_at -> |_| NaiveDate::from_ymd_opt(2020, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap(),
     }
 }
 
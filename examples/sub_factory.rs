@@ -2,13 +2,13 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct File {
-    id: u16,
+    id: u64,
     path: String,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct User {
-    id: u16,
+    id: u64,
     name: String,
     file: File,
 }
@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Post {
-    id: u16,
+    id: u64,
     title: String,
     approved: bool,
     tags: Vec<Tag>,
@@ -10,7 +10,7 @@ pub struct Post {
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Tag {
-    id: u16,
+    id: u64,
     name: String,
 }
 
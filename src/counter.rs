@@ -0,0 +1,300 @@
+use crate::factory::{GenFunc, Hook, SyncGenFunc, SyncHook};
+use std::cell::Cell;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+/// A counter of sequence numbers used by [Factory](struct.Factory.html).
+///
+/// `Cell<u64>` is used by default. If you want to share a factory across threads,
+/// you can use `AtomicU64` via [SyncFactory](type.SyncFactory.html).
+///
+/// A counter also decides the functions which a factory keeps. Thread-safe counters need
+/// `Send + Sync` functions, and the others accept any function, such as one capturing an `Rc`.
+pub trait Counter {
+    /// The type of field generators of a factory with the counter.
+    type GenFunc<T, Tr>: Fn(&mut T, u64, &Tr);
+
+    /// The type of hooks of a factory with the counter.
+    type Hook<T>: Fn(&mut T, u64);
+
+    #[doc(hidden)]
+    fn gen_func<T, Tr, F>(f: F) -> Self::GenFunc<T, Tr>
+    where
+        F: Fn(&mut T, u64, &Tr) + Send + Sync + 'static;
+
+    #[doc(hidden)]
+    fn hook<T, F>(f: F) -> Self::Hook<T>
+    where
+        F: Fn(&mut T, u64) + Send + Sync + 'static;
+
+    /// Returns a field generator which calls `first` and then `second`.
+    #[doc(hidden)]
+    fn chain<T, Tr>(
+        first: Self::GenFunc<T, Tr>,
+        second: Self::GenFunc<T, Tr>,
+    ) -> Self::GenFunc<T, Tr>
+    where
+        T: 'static,
+        Tr: 'static;
+
+    /// Creates a counter which starts from `n`.
+    fn new(n: u64) -> Self;
+
+    /// Returns the current sequence number.
+    fn get(&self) -> u64;
+
+    /// Sets the current sequence number.
+    fn set(&self, n: u64);
+
+    /// Returns the current sequence number and advances the counter.
+    ///
+    /// Returns `None` if the sequence number overflows `u64`.
+    fn next(&self) -> Option<u64>;
+}
+
+impl Counter for Cell<u64> {
+    type GenFunc<T, Tr> = GenFunc<T, Tr>;
+    type Hook<T> = Hook<T>;
+
+    fn gen_func<T, Tr, F>(f: F) -> Self::GenFunc<T, Tr>
+    where
+        F: Fn(&mut T, u64, &Tr) + Send + Sync + 'static,
+    {
+        Box::new(f)
+    }
+
+    fn hook<T, F>(f: F) -> Self::Hook<T>
+    where
+        F: Fn(&mut T, u64) + Send + Sync + 'static,
+    {
+        Box::new(f)
+    }
+
+    fn chain<T, Tr>(
+        first: Self::GenFunc<T, Tr>,
+        second: Self::GenFunc<T, Tr>,
+    ) -> Self::GenFunc<T, Tr>
+    where
+        T: 'static,
+        Tr: 'static,
+    {
+        Box::new(move |model, n, transient| {
+            first(model, n, transient);
+            second(model, n, transient);
+        })
+    }
+
+    fn new(n: u64) -> Self {
+        Cell::new(n)
+    }
+
+    fn get(&self) -> u64 {
+        Cell::get(self)
+    }
+
+    fn set(&self, n: u64) {
+        Cell::set(self, n)
+    }
+
+    fn next(&self) -> Option<u64> {
+        let n = Cell::get(self);
+        Cell::set(self, n.checked_add(1)?);
+        Some(n)
+    }
+}
+
+impl Counter for AtomicU64 {
+    type GenFunc<T, Tr> = SyncGenFunc<T, Tr>;
+    type Hook<T> = SyncHook<T>;
+
+    fn gen_func<T, Tr, F>(f: F) -> Self::GenFunc<T, Tr>
+    where
+        F: Fn(&mut T, u64, &Tr) + Send + Sync + 'static,
+    {
+        Box::new(f)
+    }
+
+    fn hook<T, F>(f: F) -> Self::Hook<T>
+    where
+        F: Fn(&mut T, u64) + Send + Sync + 'static,
+    {
+        Box::new(f)
+    }
+
+    fn chain<T, Tr>(
+        first: Self::GenFunc<T, Tr>,
+        second: Self::GenFunc<T, Tr>,
+    ) -> Self::GenFunc<T, Tr>
+    where
+        T: 'static,
+        Tr: 'static,
+    {
+        Box::new(move |model, n, transient| {
+            first(model, n, transient);
+            second(model, n, transient);
+        })
+    }
+
+    fn new(n: u64) -> Self {
+        AtomicU64::new(n)
+    }
+
+    fn get(&self) -> u64 {
+        self.load(Ordering::SeqCst)
+    }
+
+    fn set(&self, n: u64) {
+        self.store(n, Ordering::SeqCst)
+    }
+
+    fn next(&self) -> Option<u64> {
+        self.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_add(1))
+            .ok()
+    }
+}
+
+/// A counter which can be shared by several factories in a thread.
+impl<C> Counter for Rc<C>
+where
+    C: Counter,
+{
+    type GenFunc<T, Tr> = C::GenFunc<T, Tr>;
+    type Hook<T> = C::Hook<T>;
+
+    fn gen_func<T, Tr, F>(f: F) -> Self::GenFunc<T, Tr>
+    where
+        F: Fn(&mut T, u64, &Tr) + Send + Sync + 'static,
+    {
+        C::gen_func(f)
+    }
+
+    fn hook<T, F>(f: F) -> Self::Hook<T>
+    where
+        F: Fn(&mut T, u64) + Send + Sync + 'static,
+    {
+        C::hook(f)
+    }
+
+    fn chain<T, Tr>(
+        first: Self::GenFunc<T, Tr>,
+        second: Self::GenFunc<T, Tr>,
+    ) -> Self::GenFunc<T, Tr>
+    where
+        T: 'static,
+        Tr: 'static,
+    {
+        C::chain(first, second)
+    }
+
+    fn new(n: u64) -> Self {
+        Rc::new(C::new(n))
+    }
+
+    fn get(&self) -> u64 {
+        C::get(self)
+    }
+
+    fn set(&self, n: u64) {
+        C::set(self, n)
+    }
+
+    fn next(&self) -> Option<u64> {
+        C::next(self)
+    }
+}
+
+/// A counter which can be shared by several factories across threads.
+impl<C> Counter for Arc<C>
+where
+    C: Counter,
+{
+    type GenFunc<T, Tr> = C::GenFunc<T, Tr>;
+    type Hook<T> = C::Hook<T>;
+
+    fn gen_func<T, Tr, F>(f: F) -> Self::GenFunc<T, Tr>
+    where
+        F: Fn(&mut T, u64, &Tr) + Send + Sync + 'static,
+    {
+        C::gen_func(f)
+    }
+
+    fn hook<T, F>(f: F) -> Self::Hook<T>
+    where
+        F: Fn(&mut T, u64) + Send + Sync + 'static,
+    {
+        C::hook(f)
+    }
+
+    fn chain<T, Tr>(
+        first: Self::GenFunc<T, Tr>,
+        second: Self::GenFunc<T, Tr>,
+    ) -> Self::GenFunc<T, Tr>
+    where
+        T: 'static,
+        Tr: 'static,
+    {
+        C::chain(first, second)
+    }
+
+    fn new(n: u64) -> Self {
+        Arc::new(C::new(n))
+    }
+
+    fn get(&self) -> u64 {
+        C::get(self)
+    }
+
+    fn set(&self, n: u64) {
+        C::set(self, n)
+    }
+
+    fn next(&self) -> Option<u64> {
+        C::next(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::counter::Counter;
+    use std::cell::Cell;
+    use std::rc::Rc;
+    use std::sync::atomic::AtomicU64;
+
+    #[test]
+    fn test_cell_counter() {
+        let counter: Cell<u64> = Counter::new(1);
+        assert_eq!(counter.next(), Some(1));
+        assert_eq!(counter.next(), Some(2));
+        assert_eq!(Counter::get(&counter), 3);
+        Counter::set(&counter, 10);
+        assert_eq!(counter.next(), Some(10));
+    }
+
+    #[test]
+    fn test_atomic_counter() {
+        let counter: AtomicU64 = Counter::new(1);
+        assert_eq!(counter.next(), Some(1));
+        assert_eq!(counter.next(), Some(2));
+        assert_eq!(counter.get(), 3);
+        counter.set(10);
+        assert_eq!(counter.next(), Some(10));
+    }
+
+    #[test]
+    fn test_counter_overflow() {
+        let cell: Cell<u64> = Counter::new(u64::MAX);
+        let atomic: AtomicU64 = Counter::new(u64::MAX);
+        assert_eq!(cell.next(), None);
+        assert_eq!(atomic.next(), None);
+    }
+
+    #[test]
+    fn test_shared_counter() {
+        let counter: Rc<Cell<u64>> = Counter::new(1);
+        let shared = counter.clone();
+        assert_eq!(counter.next(), Some(1));
+        assert_eq!(shared.next(), Some(2));
+        assert_eq!(Counter::get(&counter), 3);
+    }
+}
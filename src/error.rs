@@ -0,0 +1,147 @@
+use std::fmt;
+
+/// An error which occurs while building a struct.
+#[derive(Debug)]
+pub enum Error {
+    /// A struct couldn't be serialized into a template.
+    Serialization(serde_json::Error),
+    /// A struct couldn't be deserialized from a template.
+    Deserialization {
+        /// JSON which couldn't be deserialized.
+        json: String,
+        /// Path to the field which couldn't be deserialized, such as `file.path`.
+        path: String,
+        source: serde_json::Error,
+    },
+    /// A sequence number overflowed `u64`.
+    SequenceOverflow,
+    /// A sequence number is 0, although sequence numbers start from 1.
+    ZeroSequence,
+    /// A seed of [sequence_a](fn.sequence_a.html) is not an alphabet.
+    InvalidAlphabet(String),
+    /// A trait is not defined in a factory.
+    UnknownTrait(String),
+    /// A variant is not defined in a factory of an enum.
+    UnknownVariant(String),
+    /// A path of an override doesn't point to a field, such as `file.name` of a `File` without `name`.
+    UnknownField(String),
+    /// A factory is not registered with [register](fn.register.html).
+    UnknownFactory {
+        name: String,
+        /// Names of the registered factories.
+        registered: Vec<String>,
+    },
+    /// A registered factory doesn't build the requested type.
+    FactoryType {
+        name: String,
+        /// Name of the requested type.
+        expected: &'static str,
+    },
+    /// A registered factory keeps structs with `clone`, so it can't build JSON.
+    NotSerializable(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Serialization(source) => write!(f, "Failed to serialize a model: {}", source),
+            Error::Deserialization { json, path, source } => write!(
+                f,
+                "Failed to deserialize a model at `{}`: {}. JSON: {}",
+                path, source, json
+            ),
+            Error::SequenceOverflow => {
+                write!(
+                    f,
+                    "Sequence number overflowed. It must be less than {}.",
+                    u64::MAX
+                )
+            }
+            Error::ZeroSequence => write!(f, "Sequence number is 0. It must start from 1."),
+            Error::InvalidAlphabet(from) => {
+                write!(f, "Unexpected value `{}`. Please use an alphabet.", from)
+            }
+            Error::UnknownTrait(name) => write!(
+                f,
+                "Unexpected trait `{}`. Please define it in the factory.",
+                name
+            ),
+            Error::UnknownVariant(name) => write!(
+                f,
+                "Unexpected variant `{}`. Please define it in the factory.",
+                name
+            ),
+            Error::UnknownField(path) => write!(
+                f,
+                "Unexpected field `{}`. Please check the path of the override.",
+                path
+            ),
+            Error::UnknownFactory { name, registered } => write!(
+                f,
+                "Unexpected factory `{}`. Registered factories: [{}]",
+                name,
+                registered
+                    .iter()
+                    .map(|name| format!("`{}`", name))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Error::FactoryType { name, expected } => {
+                write!(f, "Factory `{}` doesn't build `{}`.", name, expected)
+            }
+            Error::NotSerializable(name) => write!(
+                f,
+                "Factory `{}` keeps structs with `clone`, so it can't build JSON. Please use `build`.",
+                name
+            ),
+        }
+    }
+}
+
+/// An error which occurs while creating a struct with [try_create](struct.Factory.html#method.try_create).
+#[derive(Debug)]
+pub enum CreateError<E> {
+    /// The struct couldn't be built.
+    Build(Error),
+    /// A persister couldn't save the built struct.
+    Persist(E),
+}
+
+impl<E> fmt::Display for CreateError<E>
+where
+    E: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CreateError::Build(err) => write!(f, "Failed to build a struct: {}", err),
+            CreateError::Persist(err) => write!(f, "Failed to save a struct: {}", err),
+        }
+    }
+}
+
+impl<E> std::error::Error for CreateError<E>
+where
+    E: std::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CreateError::Build(err) => Some(err),
+            CreateError::Persist(err) => Some(err),
+        }
+    }
+}
+
+impl<E> From<Error> for CreateError<E> {
+    fn from(err: Error) -> Self {
+        CreateError::Build(err)
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Serialization(source) | Error::Deserialization { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
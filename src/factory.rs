@@ -1,29 +1,183 @@
+use crate::counter::Counter;
+use crate::error::{CreateError, Error};
+use crate::persister::Persister;
+use crate::stub::STUB_ID_START;
+use crate::template::Template;
 use crate::variable;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
-use std::cell::Cell;
+use std::cell::{Cell, RefCell};
 use std::marker::PhantomData;
+use std::ops::Deref;
+use std::sync::atomic::AtomicU64;
 
-pub struct Factory<'a, T>
+/// A function which sets attributes of a struct with a sequence number and transient attributes.
+pub type GenFunc<T, Tr = ()> = Box<dyn Fn(&mut T, u64, &Tr)>;
+
+/// A function which post-processes a built struct with its sequence number.
+pub type Hook<T> = Box<dyn Fn(&mut T, u64)>;
+
+/// A [GenFunc](type.GenFunc.html) which a [SyncFactory](type.SyncFactory.html) can share across threads.
+pub type SyncGenFunc<T, Tr = ()> = Box<dyn Fn(&mut T, u64, &Tr) + Send + Sync>;
+
+/// A [Hook](type.Hook.html) which a [SyncFactory](type.SyncFactory.html) can share across threads.
+pub type SyncHook<T> = Box<dyn Fn(&mut T, u64) + Send + Sync>;
+
+pub struct Factory<'a, T, Tr = (), S = Cell<u64>, M = String>
 where
-    T: Serialize + Deserialize<'a>,
+    S: Counter,
 {
-    pub model: String,
-    pub sequence: Cell<u16>,
-    pub gen_func: Box<dyn Fn(&mut T, u16)>,
+    pub model: M,
+    pub sequence: S,
+    pub gen_func: S::GenFunc<T, Tr>,
+    pub traits: Vec<(&'static str, S::GenFunc<T, Tr>)>,
+    pub variants: Vec<(&'static str, S::GenFunc<T, Tr>)>,
+    pub after_build: Vec<S::Hook<T>>,
+    pub before_create: Vec<S::Hook<T>>,
+    pub after_create: Vec<S::Hook<T>>,
+    pub stub_sequence: S,
+    pub stub_func: S::GenFunc<T, Tr>,
     pub _maker: PhantomData<&'a T>,
 }
 
+/// A factory defined by [define](macro.define.html).
+///
+/// A child factory uses it to inherit fields, traits and hooks from its parent.
+pub trait Definition {
+    /// The struct which the factory builds.
+    type Struct;
+    /// The transient attributes of the factory.
+    type Transient;
+    /// The template which the factory keeps the struct as.
+    type Template;
+
+    #[doc(hidden)]
+    fn factory<'a, S: Counter>() -> Factory<'a, Self::Struct, Self::Transient, S, Self::Template>;
+}
+
+/// Lets generators of a child factory read the fields which its parents generated.
+///
+/// The context of a child generator dereferences to the scope, which has the transient attributes
+/// as references and dereferences to the struct.
 #[doc(hidden)]
-pub fn new<'a, T>(model: T, suite: Box<dyn Fn(&mut T, u16)>) -> Factory<'a, T>
+pub trait Scope<T> {
+    type Scope<'t>: Deref<Target = T>
+    where
+        Self: 't,
+        T: 't;
+
+    fn scope<'t>(transient: &'t Self, model: &'t T) -> Self::Scope<'t>;
+}
+
+/// The scope of a factory without transient attributes.
+#[doc(hidden)]
+pub struct ModelScope<'t, T> {
+    __beaver_model: &'t T,
+}
+
+impl<T> Deref for ModelScope<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.__beaver_model
+    }
+}
+
+impl<T> Scope<T> for () {
+    type Scope<'t>
+        = ModelScope<'t, T>
+    where
+        T: 't;
+
+    fn scope<'t>(_: &'t Self, model: &'t T) -> ModelScope<'t, T> {
+        ModelScope {
+            __beaver_model: model,
+        }
+    }
+}
+
+/// A [Factory](struct.Factory.html) which can be shared across threads.
+///
+/// Its sequence number is an atomic counter, so every thread gets a unique sequence number.
+/// Its field generators and hooks must be `Send + Sync`, which a [Factory](struct.Factory.html) with
+/// the default counter doesn't require.
+///
+/// # Usage
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Post {
+///     id: u64,
+///     title: String,
+/// }
+///
+/// beaver::define! {
+///     PostFactory (Post) {
+///         id -> |n| n,
+///         title -> |n| format!("post-{}", n),
+///     }
+/// }
+///
+/// let post_factory = PostFactory::new_sync();
+/// std::thread::scope(|s| {
+///     for _ in 0..4 {
+///         s.spawn(|| post_factory.build_list(10, |_| {}));
+///     }
+/// });
+/// ```
+pub type SyncFactory<'a, T, Tr = (), M = String> = Factory<'a, T, Tr, AtomicU64, M>;
+
+#[doc(hidden)]
+pub fn new<'a, T, Tr>(model: T, suite: GenFunc<T, Tr>) -> Factory<'a, T, Tr>
 where
     T: Serialize + Deserialize<'a>,
 {
-    Factory {
-        model: serde_json::to_string(&model).unwrap(),
-        sequence: Cell::new(1),
+    new_with_counter(model, suite)
+}
+
+#[doc(hidden)]
+pub fn try_new<'a, T, Tr>(model: T, suite: GenFunc<T, Tr>) -> Result<Factory<'a, T, Tr>, Error>
+where
+    T: Serialize + Deserialize<'a>,
+{
+    try_new_with_counter(model, suite)
+}
+
+#[doc(hidden)]
+pub fn new_with_counter<'a, T, Tr, S, M>(
+    model: T,
+    suite: S::GenFunc<T, Tr>,
+) -> Factory<'a, T, Tr, S, M>
+where
+    S: Counter,
+    M: Template<'a, T>,
+{
+    try_new_with_counter(model, suite).unwrap_or_else(|err| panic!("{}", err))
+}
+
+#[doc(hidden)]
+pub fn try_new_with_counter<'a, T, Tr, S, M>(
+    model: T,
+    suite: S::GenFunc<T, Tr>,
+) -> Result<Factory<'a, T, Tr, S, M>, Error>
+where
+    S: Counter,
+    M: Template<'a, T>,
+{
+    Ok(Factory {
+        model: M::new(model)?,
+        sequence: S::new(1),
         gen_func: suite,
+        traits: vec![],
+        variants: vec![],
+        after_build: vec![],
+        before_create: vec![],
+        after_create: vec![],
+        stub_sequence: S::new(STUB_ID_START),
+        stub_func: S::gen_func(|_, _, _| {}),
         _maker: PhantomData,
-    }
+    })
 }
 
 /// Returns a consecutive term. `from` is the first term.
@@ -35,7 +189,7 @@ where
 ///
 /// #[derive(Serialize, Deserialize)]
 /// struct Post {
-///     id: u16,
+///     id: u64,
 ///     title: String,
 ///     approved: bool,
 ///     created_at: NaiveDateTime,
@@ -48,12 +202,23 @@ where
 ///         // Second post's title: "post-101"
 ///         title -> |n| format!("post-{}", beaver::sequence(100, n)),
 ///         approved -> |_| false,
-///         created_at -> |_| NaiveDate::from_ymd(2020, 1, 1).and_hms(0, 0, 0),
+///         created_at -> |_| NaiveDate::from_ymd_opt(2020, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap(),
 ///     }
 /// }
 /// ```
-pub fn sequence(from: u16, n: u16) -> u16 {
-    from + n - 1
+///
+/// # Panics
+/// Panics if `n` is 0 or the term overflows `u64`.
+pub fn sequence(from: u64, n: u64) -> u64 {
+    n.checked_sub(1)
+        .unwrap_or_else(|| panic!("{}", Error::ZeroSequence))
+        .checked_add(from)
+        .unwrap_or_else(|| {
+            panic!(
+                "Sequence number overflowed. `from` is {} and `n` is {}.",
+                from, n
+            )
+        })
 }
 
 /// Returns a consecutive letter. `from` is the first letter.
@@ -70,7 +235,7 @@ pub fn sequence(from: u16, n: u16) -> u16 {
 ///
 /// #[derive(Serialize, Deserialize)]
 /// struct Post {
-///     id: u16,
+///     id: u64,
 ///     title: String,
 ///     approved: bool,
 ///     created_at: NaiveDateTime,
@@ -83,18 +248,121 @@ pub fn sequence(from: u16, n: u16) -> u16 {
 ///         // Second post's title: "post-b"
 ///         title -> |n| format!("post-{}", beaver::sequence_a("a", n)),
 ///         approved -> |_| false,
-///         created_at -> |_| NaiveDate::from_ymd(2020, 1, 1).and_hms(0, 0, 0),
+///         created_at -> |_| NaiveDate::from_ymd_opt(2020, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap(),
 ///     }
 /// }
 /// ```
-pub fn sequence_a(from: &str, n: u16) -> String {
-    to_alphabet(
-        *variable::ALPHABET_INDEX
-            .get(from)
-            .unwrap_or_else(|| panic!("Unexpected value `{}`. Please use an alphabet.", from))
-            as u128
-            + (n - 1) as u128,
-    )
+///
+/// # Panics
+/// Panics if `from` is not an alphabet or `n` is 0.
+pub fn sequence_a(from: &str, n: u64) -> String {
+    try_sequence_a(from, n).unwrap_or_else(|err| panic!("{}", err))
+}
+
+/// Returns a consecutive letter like [sequence_a](fn.sequence_a.html), but returns an error
+/// instead of panicking.
+///
+/// # Usage
+/// ```rust
+/// assert_eq!(beaver::try_sequence_a("z", 2).unwrap(), "aa");
+/// assert!(matches!(
+///     beaver::try_sequence_a("1", 1),
+///     Err(beaver::Error::InvalidAlphabet(_))
+/// ));
+/// ```
+pub fn try_sequence_a(from: &str, n: u64) -> Result<String, Error> {
+    let index = *variable::ALPHABET_INDEX
+        .get(from)
+        .ok_or_else(|| Error::InvalidAlphabet(from.to_string()))?;
+    let n = n.checked_sub(1).ok_or(Error::ZeroSequence)?;
+    Ok(to_alphabet(index as u128 + n as u128))
+}
+
+/// Returns one of `values` in turn. The first value is returned when `n` is 1.
+///
+/// # Example
+/// - If `values` is `["admin", "editor"]` and `n` is 1, this function returns "admin".
+/// - If `values` is `["admin", "editor"]` and `n` is 2, this function returns "editor".
+/// - If `values` is `["admin", "editor"]` and `n` is 3, this function returns "admin".
+///
+/// # Usage
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct User {
+///     id: u64,
+///     role: String,
+/// }
+///
+/// beaver::define! {
+///     UserFactory (User) {
+///         id -> |n| n,
+///         // Roles of users: "admin", "editor", "viewer", "admin", ...
+///         role -> |n| beaver::cycle(&["admin", "editor", "viewer"], n).to_string(),
+///     }
+/// }
+///
+/// let user_factory = UserFactory::new();
+/// let users = user_factory.build_list(4, |_| {});
+/// assert_eq!(users[2].role, "viewer");
+/// assert_eq!(users[3].role, "admin");
+/// ```
+///
+/// # Panics
+/// Panics if `values` is empty or `n` is 0.
+pub fn cycle<T>(values: &[T], n: u64) -> T
+where
+    T: Clone,
+{
+    assert!(!values.is_empty(), "`values` of cycle must not be empty.");
+    let n = n
+        .checked_sub(1)
+        .unwrap_or_else(|| panic!("{}", Error::ZeroSequence));
+    values[(n % values.len() as u64) as usize].clone()
+}
+
+/// Returns one of `values` in turn, repeating each value as many times as its weight.
+/// The first value is returned when `n` is 1.
+///
+/// # Example
+/// - If `values` is `[("active", 2), ("banned", 1)]`, this function returns "active", "active",
+///   "banned", "active", ... as `n` advances from 1.
+///
+/// # Usage
+/// ```rust
+/// let statuses = [("active", 2), ("banned", 1)];
+/// assert_eq!(beaver::cycle_weighted(&statuses, 1), "active");
+/// assert_eq!(beaver::cycle_weighted(&statuses, 2), "active");
+/// assert_eq!(beaver::cycle_weighted(&statuses, 3), "banned");
+/// assert_eq!(beaver::cycle_weighted(&statuses, 4), "active");
+/// ```
+///
+/// # Panics
+/// Panics if the sum of the weights is 0, it overflows `u64`, or `n` is 0.
+pub fn cycle_weighted<T>(values: &[(T, u64)], n: u64) -> T
+where
+    T: Clone,
+{
+    let total = values
+        .iter()
+        .try_fold(0u64, |total, (_, weight)| total.checked_add(*weight))
+        .unwrap_or_else(|| panic!("The sum of weights of cycle_weighted overflowed."));
+    assert!(
+        total > 0,
+        "The sum of weights of cycle_weighted must not be 0."
+    );
+    let mut index = n
+        .checked_sub(1)
+        .unwrap_or_else(|| panic!("{}", Error::ZeroSequence))
+        % total;
+    for (value, weight) in values {
+        if index < *weight {
+            return value.clone();
+        }
+        index -= weight;
+    }
+    unreachable!()
 }
 
 /// Converts a number to Excel like base 26.
@@ -115,72 +383,830 @@ fn to_alphabet(n: u128) -> String {
     }
 }
 
-impl<'a, T> Factory<'a, T>
+impl<'a, T, Tr, S, M> Factory<'a, T, Tr, S, M>
 where
-    T: Serialize + Deserialize<'a>,
+    Tr: Default,
+    S: Counter,
+    M: Template<'a, T>,
 {
+    #[doc(hidden)]
+    pub fn with_trait(mut self, name: &'static str, suite: S::GenFunc<T, Tr>) -> Self {
+        self.traits.push((name, suite));
+        self
+    }
+
+    #[doc(hidden)]
+    pub fn with_variant(mut self, name: &'static str, suite: S::GenFunc<T, Tr>) -> Self {
+        self.variants.push((name, suite));
+        self
+    }
+
+    #[doc(hidden)]
+    pub fn with_fields(mut self, suite: S::GenFunc<T, Tr>) -> Self
+    where
+        T: 'static,
+        Tr: 'static,
+    {
+        self.gen_func = S::chain(self.gen_func, suite);
+        self
+    }
+
+    /// Resets the sequence number to 1.
+    ///
+    /// # Usage
+    /// ```rust
+    /// use serde::{Deserialize, Serialize};
+    ///
+    /// #[derive(Serialize, Deserialize)]
+    /// struct Post {
+    ///     id: u64,
+    /// }
+    ///
+    /// beaver::define! {
+    ///     PostFactory (Post) {
+    ///         id -> |n| n,
+    ///     }
+    /// }
+    ///
+    /// let post_factory = PostFactory::new();
+    /// post_factory.build_list(3, |_| {});
+    /// post_factory.reset_sequence();
+    /// assert_eq!(post_factory.build(|_| {}).id, 1);
+    /// post_factory.set_sequence(100);
+    /// assert_eq!(post_factory.build(|_| {}).id, 100);
+    /// ```
+    pub fn reset_sequence(&self) {
+        self.sequence.set(1)
+    }
+
+    /// Sets the sequence number which the next struct is built with.
+    pub fn set_sequence(&self, n: u64) {
+        self.sequence.set(n)
+    }
+
+    /// Replaces the counter of sequence numbers.
+    ///
+    /// If you pass a shared counter such as `Rc<Cell<u64>>` or `Arc<AtomicU64>` to several factories,
+    /// they share sequence numbers. It is useful to give a child factory the sequence of its parent.
+    /// The counter must be as thread-safe as the current one, e.g. `Arc<AtomicU64>` for a
+    /// [SyncFactory](type.SyncFactory.html), since it decides the functions which the factory keeps.
+    ///
+    /// # Usage
+    /// ```rust
+    /// use serde::{Deserialize, Serialize};
+    /// use std::cell::Cell;
+    /// use std::rc::Rc;
+    ///
+    /// #[derive(Serialize, Deserialize)]
+    /// struct Post {
+    ///     id: u64,
+    ///     approved: bool,
+    /// }
+    ///
+    /// beaver::define! {
+    ///     PostFactory (Post) {
+    ///         id -> |n| n,
+    ///         approved -> |_| false,
+    ///     }
+    /// }
+    ///
+    /// beaver::define! {
+    ///     ApprovedPostFactory (Post) : PostFactory {
+    ///         approved -> |_| true,
+    ///     }
+    /// }
+    ///
+    /// let sequence = Rc::new(Cell::new(1));
+    /// let post_factory = PostFactory::new().with_sequence(sequence.clone());
+    /// let approved_post_factory = ApprovedPostFactory::new().with_sequence(sequence);
+    /// assert_eq!(post_factory.build(|_| {}).id, 1);
+    /// assert_eq!(approved_post_factory.build(|_| {}).id, 2);
+    /// ```
+    pub fn with_sequence<C>(self, sequence: C) -> Factory<'a, T, Tr, C, M>
+    where
+        C: Counter<GenFunc<T, Tr> = S::GenFunc<T, Tr>, Hook<T> = S::Hook<T>>,
+    {
+        Factory {
+            model: self.model,
+            sequence,
+            gen_func: self.gen_func,
+            traits: self.traits,
+            variants: self.variants,
+            after_build: self.after_build,
+            before_create: self.before_create,
+            after_create: self.after_create,
+            stub_sequence: C::new(self.stub_sequence.get()),
+            stub_func: self.stub_func,
+            _maker: PhantomData,
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn with_stub(mut self, from: u64, suite: S::GenFunc<T, Tr>) -> Self {
+        self.stub_sequence = S::new(from);
+        self.stub_func = suite;
+        self
+    }
+
+    /// Applies `suite` after the current stub function, as a child factory does.
+    /// Stub ids keep their range unless `from` is given.
+    #[doc(hidden)]
+    pub fn with_stub_fields(mut self, from: Option<u64>, suite: S::GenFunc<T, Tr>) -> Self
+    where
+        T: 'static,
+        Tr: 'static,
+    {
+        if let Some(from) = from {
+            self.stub_sequence = S::new(from);
+        }
+        self.stub_func = S::chain(self.stub_func, suite);
+        self
+    }
+
+    #[doc(hidden)]
+    pub fn with_after_build(mut self, hook: S::Hook<T>) -> Self {
+        self.after_build.push(hook);
+        self
+    }
+
+    #[doc(hidden)]
+    pub fn with_before_create(mut self, hook: S::Hook<T>) -> Self {
+        self.before_create.push(hook);
+        self
+    }
+
+    #[doc(hidden)]
+    pub fn with_after_create(mut self, hook: S::Hook<T>) -> Self {
+        self.after_create.push(hook);
+        self
+    }
+
     /// Builds a struct from [Factory](struct.Factory.html).
+    ///
+    /// # Panics
+    /// Panics if the struct can't be instantiated from the template.
+    /// Use [try_build](struct.Factory.html#method.try_build) to handle the error.
     pub fn build<O>(&'a self, f: O) -> T
     where
         O: Fn(&mut T),
     {
-        let mut model = serde_json::from_str(self.model.as_str()).unwrap();
-        let suite = &self.gen_func;
-        suite(&mut model, self.sequence.get());
-        f(&mut model);
-        self.sequence.set(self.sequence.get() + 1);
-        model
+        self.build_with_traits(&[], f)
+    }
+
+    /// Builds a struct from [Factory](struct.Factory.html), returning an error instead of panicking.
+    ///
+    /// # Usage
+    /// ```rust
+    /// use serde::{Deserialize, Serialize};
+    ///
+    /// #[derive(Serialize, Deserialize, Debug)]
+    /// struct Post {
+    ///     id: u64,
+    ///     title: String,
+    /// }
+    ///
+    /// beaver::define! {
+    ///     PostFactory (Post) {
+    ///         id -> |n| n,
+    ///         title -> |n| format!("post-{}", n),
+    ///     }
+    /// }
+    ///
+    /// let mut post_factory = PostFactory::new();
+    /// assert_eq!(post_factory.try_build(|_| {}).unwrap().id, 1);
+    ///
+    /// post_factory.model = r#"{"id":1,"title":null}"#.to_string();
+    /// match post_factory.try_build(|_| {}) {
+    ///     Err(beaver::Error::Deserialization { path, .. }) => assert_eq!(path, "title"),
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    pub fn try_build<O>(&'a self, f: O) -> Result<T, Error>
+    where
+        O: Fn(&mut T),
+    {
+        self.try_build_with_traits(&[], f)
+    }
+
+    /// Builds a struct from [Factory](struct.Factory.html) with traits.
+    ///
+    /// Traits are applied in the given order after the default attributes,
+    /// so later traits override earlier ones. `f` is applied after them,
+    /// and `after_build` hooks run last.
+    ///
+    /// # Panics
+    /// Panics if a trait is not defined in the factory.
+    pub fn build_with_traits<O>(&'a self, traits: &[&str], f: O) -> T
+    where
+        O: Fn(&mut T),
+    {
+        self.try_build_with_traits(traits, f)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Builds a struct from [Factory](struct.Factory.html) with traits,
+    /// returning an error instead of panicking.
+    pub fn try_build_with_traits<O>(&'a self, traits: &[&str], f: O) -> Result<T, Error>
+    where
+        O: Fn(&mut T),
+    {
+        let n = self.sequence.next().ok_or(Error::SequenceOverflow)?;
+        self.generate(n, &self.gen_func, None, traits, &Tr::default(), f)
+    }
+
+    /// Builds a struct from [Factory](struct.Factory.html) with overridden transient attributes.
+    ///
+    /// `t` overrides the default values of transient attributes, which are only used
+    /// to generate fields and never land in the struct.
+    pub fn build_with_transient<U, O>(&'a self, t: U, f: O) -> T
+    where
+        U: Fn(&mut Tr),
+        O: Fn(&mut T),
+    {
+        let mut transient = Tr::default();
+        t(&mut transient);
+        self.sequence
+            .next()
+            .ok_or(Error::SequenceOverflow)
+            .and_then(|n| self.generate(n, &self.gen_func, None, &[], &transient, f))
+            .unwrap_or_else(|err| panic!("{}", err))
     }
 
     #[doc(hidden)]
-    pub fn build_n<O>(&'a self, n: u16, f: O) -> T
+    pub fn build_n<O>(&'a self, n: u64, f: O) -> T
     where
         O: Fn(&mut T),
     {
-        let mut model = serde_json::from_str(self.model.as_str()).unwrap();
-        let suite = &self.gen_func;
-        suite(&mut model, n);
-        f(&mut model);
-        self.sequence.set(self.sequence.get() + 1);
-        model
+        self.sequence
+            .next()
+            .ok_or(Error::SequenceOverflow)
+            .and_then(|_| self.generate(n, &self.gen_func, None, &[], &Tr::default(), f))
+            .unwrap_or_else(|err| panic!("{}", err))
     }
 
     /// Builds a vector of structs from [Factory](struct.Factory.html).
-    pub fn build_list<O>(&'a self, number: u16, f: O) -> Vec<T>
+    pub fn build_list<O>(&'a self, number: u64, f: O) -> Vec<T>
+    where
+        O: Fn(&mut T),
+    {
+        self.build_list_with_traits(number, &[], f)
+    }
+
+    /// Builds a vector of structs from [Factory](struct.Factory.html),
+    /// returning the first error instead of panicking.
+    pub fn try_build_list<O>(&'a self, number: u64, f: O) -> Result<Vec<T>, Error>
+    where
+        O: Fn(&mut T),
+    {
+        self.try_build_list_with_traits(number, &[], f)
+    }
+
+    /// Builds a vector of structs from [Factory](struct.Factory.html) with traits.
+    ///
+    /// # Panics
+    /// Panics if a trait is not defined in the factory.
+    pub fn build_list_with_traits<O>(&'a self, number: u64, traits: &[&str], f: O) -> Vec<T>
+    where
+        O: Fn(&mut T),
+    {
+        self.try_build_list_with_traits(number, traits, f)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Builds a vector of structs from [Factory](struct.Factory.html) with traits,
+    /// returning the first error instead of panicking.
+    pub fn try_build_list_with_traits<O>(
+        &'a self,
+        number: u64,
+        traits: &[&str],
+        f: O,
+    ) -> Result<Vec<T>, Error>
     where
         O: Fn(&mut T),
     {
         let mut list = vec![];
         for _ in 0..number {
-            list.push(self.build(&f))
+            list.push(self.try_build_with_traits(traits, &f)?)
+        }
+        Ok(list)
+    }
+
+    /// Builds a vector of structs from [Factory](struct.Factory.html) with overridden transient attributes.
+    pub fn build_list_with_transient<U, O>(&'a self, number: u64, t: U, f: O) -> Vec<T>
+    where
+        U: Fn(&mut Tr),
+        O: Fn(&mut T),
+    {
+        let mut list = vec![];
+        for _ in 0..number {
+            list.push(self.build_with_transient(&t, &f))
         }
         list
     }
 
     #[doc(hidden)]
-    pub fn build_list_n<O>(&'a self, number: u16, n: u16, f: O) -> Vec<T>
+    pub fn build_list_n<O>(&'a self, number: u64, n: u64, f: O) -> Vec<T>
     where
         O: Fn(&mut T),
     {
+        let first = n
+            .checked_sub(1)
+            .unwrap_or_else(|| panic!("{}", Error::ZeroSequence))
+            .checked_mul(number)
+            .and_then(|first| first.checked_add(1))
+            .filter(|first| first.checked_add(number).is_some())
+            .unwrap_or_else(|| {
+                panic!(
+                    "Sequence number overflowed. `number` is {} and `n` is {}.",
+                    number, n
+                )
+            });
         let mut list = vec![];
-        for i in number * (n - 1) + 1..number * (n - 1) + number + 1 {
+        for i in first..first + number {
             list.push(self.build_n(i, &f))
         }
         list
     }
+
+    /// Builds a variant of an enum from a factory which [define](macro.define.html) defines for an enum.
+    ///
+    /// [build](struct.Factory.html#method.build) cycles through the variants in the defined order,
+    /// and this method always builds the variant named `variant`.
+    ///
+    /// # Usage
+    /// ```rust
+    /// use serde::{Deserialize, Serialize};
+    ///
+    /// #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    /// enum Event {
+    ///     Created { id: u64 },
+    ///     Deleted { id: u64, reason: String },
+    /// }
+    ///
+    /// beaver::define! {
+    ///     EventFactory (Event) {
+    ///         Created {
+    ///             id -> |n| n,
+    ///         },
+    ///         Deleted {
+    ///             id -> |n| n,
+    ///             reason -> |n| format!("reason-{}", n),
+    ///         },
+    ///     }
+    /// }
+    ///
+    /// let event_factory = EventFactory::new();
+    /// let event = event_factory.build_variant("Deleted", |_| {});
+    /// assert_eq!(event, Event::Deleted { id: 1, reason: "reason-1".to_string() });
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the variant is not defined in the factory.
+    pub fn build_variant<O>(&'a self, variant: &str, f: O) -> T
+    where
+        O: Fn(&mut T),
+    {
+        self.try_build_variant(variant, f)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Builds a variant of an enum like [build_variant](struct.Factory.html#method.build_variant),
+    /// returning an error instead of panicking.
+    pub fn try_build_variant<O>(&'a self, variant: &str, f: O) -> Result<T, Error>
+    where
+        O: Fn(&mut T),
+    {
+        let suite = self
+            .variants
+            .iter()
+            .find(|(name, _)| *name == variant)
+            .map(|(_, suite)| suite)
+            .ok_or_else(|| Error::UnknownVariant(variant.to_string()))?;
+        let n = self.sequence.next().ok_or(Error::SequenceOverflow)?;
+        self.generate(n, suite, None, &[], &Tr::default(), f)
+    }
+
+    /// Builds a struct which looks persisted without saving it.
+    ///
+    /// `id` is set to a stub id, and `created_at` and `updated_at` are set to the current time
+    /// if the struct has them and accepts the values. Timestamps are `SystemTime`, or
+    /// `NaiveDateTime` and `DateTime<Utc>` of chrono if you enable `chrono` feature.
+    /// Fields in the `stub` block of [define](macro.define.html) are generated with a stub id
+    /// after them, so the block can override them. Both run after traits and before `f`.
+    /// Stub ids are counted separately from the sequence number and start from 1001 by default.
+    /// A factory with the [Cloned](struct.Cloned.html) template only stubs the fields in the block.
+    ///
+    /// # Usage
+    /// ```rust
+    /// use serde::{Deserialize, Serialize};
+    /// use std::time::SystemTime;
+    ///
+    /// #[derive(Serialize, Deserialize)]
+    /// struct Post {
+    ///     id: u64,
+    ///     title: String,
+    ///     created_at: Option<SystemTime>,
+    /// }
+    ///
+    /// beaver::define! {
+    ///     PostFactory (Post) {
+    ///         id -> |_| 0,
+    ///         title -> |n| format!("post-{}", n),
+    ///         created_at -> |_| None,
+    ///     }
+    /// }
+    ///
+    /// let post_factory = PostFactory::new();
+    /// let post = post_factory.build_stubbed(|_| {});
+    /// assert_eq!(post.id, 1001);
+    /// assert_eq!(post.title, "post-1");
+    /// assert!(post.created_at.is_some());
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the struct can't be instantiated from the template.
+    pub fn build_stubbed<O>(&'a self, f: O) -> T
+    where
+        O: Fn(&mut T),
+    {
+        self.sequence
+            .next()
+            .zip(self.stub_sequence.next())
+            .ok_or(Error::SequenceOverflow)
+            .and_then(|(n, stub_id)| {
+                self.generate(n, &self.gen_func, Some(stub_id), &[], &Tr::default(), f)
+            })
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Builds a vector of structs which look persisted without saving them.
+    pub fn build_stubbed_list<O>(&'a self, number: u64, f: O) -> Vec<T>
+    where
+        O: Fn(&mut T),
+    {
+        let mut list = vec![];
+        for _ in 0..number {
+            list.push(self.build_stubbed(&f))
+        }
+        list
+    }
+
+    /// Builds a struct from [Factory](struct.Factory.html) and saves it with `persister`.
+    ///
+    /// Returns the struct returned by [Persister::save](trait.Persister.html#tymethod.save),
+    /// so values assigned by the storage flow back. `before_create` hooks run after `after_build` hooks
+    /// and before saving, and `after_create` hooks run on the saved struct.
+    ///
+    /// # Panics
+    /// Panics if the struct can't be instantiated from the template.
+    /// Use [try_create](struct.Factory.html#method.try_create) to handle the error.
+    pub fn create<P, O>(&'a self, persister: &mut P, f: O) -> Result<T, P::Error>
+    where
+        P: Persister<T>,
+        O: Fn(&mut T),
+    {
+        self.try_create(persister, f).map_err(|err| match err {
+            CreateError::Build(err) => panic!("{}", err),
+            CreateError::Persist(err) => err,
+        })
+    }
+
+    /// Builds a struct and saves it with `persister` like [create](struct.Factory.html#method.create),
+    /// returning an error of building instead of panicking.
+    ///
+    /// # Usage
+    /// ```rust
+    /// use serde::{Deserialize, Serialize};
+    ///
+    /// #[derive(Serialize, Deserialize, Clone)]
+    /// struct Post {
+    ///     id: u64,
+    ///     title: String,
+    /// }
+    ///
+    /// beaver::define! {
+    ///     PostFactory (Post) {
+    ///         id -> |n| n,
+    ///         title -> |n| format!("post-{}", n),
+    ///     }
+    /// }
+    ///
+    /// let mut posts = vec![];
+    /// let post_factory = PostFactory::new();
+    /// post_factory.set_sequence(u64::MAX);
+    /// match post_factory.try_create(&mut posts, |_| {}) {
+    ///     Err(beaver::CreateError::Build(beaver::Error::SequenceOverflow)) => {}
+    ///     _ => unreachable!(),
+    /// }
+    /// assert!(posts.is_empty());
+    /// ```
+    pub fn try_create<P, O>(&'a self, persister: &mut P, f: O) -> Result<T, CreateError<P::Error>>
+    where
+        P: Persister<T>,
+        O: Fn(&mut T),
+    {
+        let n = self.sequence.next().ok_or(Error::SequenceOverflow)?;
+        let mut model = self.generate(n, &self.gen_func, None, &[], &Tr::default(), f)?;
+        for hook in &self.before_create {
+            hook(&mut model, n);
+        }
+        let mut model = persister.save(model).map_err(CreateError::Persist)?;
+        for hook in &self.after_create {
+            hook(&mut model, n);
+        }
+        Ok(model)
+    }
+
+    /// Builds a vector of structs from [Factory](struct.Factory.html) and saves them with `persister`.
+    ///
+    /// Stops at the first error of `persister`.
+    pub fn create_list<P, O>(
+        &'a self,
+        number: u64,
+        persister: &mut P,
+        f: O,
+    ) -> Result<Vec<T>, P::Error>
+    where
+        P: Persister<T>,
+        O: Fn(&mut T),
+    {
+        let mut list = vec![];
+        for _ in 0..number {
+            list.push(self.create(persister, &f)?)
+        }
+        Ok(list)
+    }
+
+    /// Builds a vector of structs and saves them with `persister` like
+    /// [create_list](struct.Factory.html#method.create_list), returning an error of building instead of panicking.
+    pub fn try_create_list<P, O>(
+        &'a self,
+        number: u64,
+        persister: &mut P,
+        f: O,
+    ) -> Result<Vec<T>, CreateError<P::Error>>
+    where
+        P: Persister<T>,
+        O: Fn(&mut T),
+    {
+        let mut list = vec![];
+        for _ in 0..number {
+            list.push(self.try_create(persister, &f)?)
+        }
+        Ok(list)
+    }
+
+    fn generate<O>(
+        &'a self,
+        n: u64,
+        suite: &S::GenFunc<T, Tr>,
+        stub_id: Option<u64>,
+        traits: &[&str],
+        transient: &Tr,
+        f: O,
+    ) -> Result<T, Error>
+    where
+        O: Fn(&mut T),
+    {
+        let mut model = self.model.instantiate()?;
+        suite(&mut model, n, transient);
+        for name in traits {
+            let suite = self.find_trait(name)?;
+            suite(&mut model, n, transient);
+        }
+        if let Some(stub_id) = stub_id {
+            model = M::stub(model, stub_id)?;
+            let suite = &self.stub_func;
+            suite(&mut model, stub_id, transient);
+        }
+        f(&mut model);
+        for hook in &self.after_build {
+            hook(&mut model, n);
+        }
+        Ok(model)
+    }
+
+    fn find_trait(&self, name: &str) -> Result<&S::GenFunc<T, Tr>, Error> {
+        // Traits of a child factory are added later, so they override the parent's ones.
+        self.traits
+            .iter()
+            .rev()
+            .find(|(trait_name, _)| *trait_name == name)
+            .map(|(_, suite)| suite)
+            .ok_or_else(|| Error::UnknownTrait(name.to_string()))
+    }
+}
+
+impl<'a, T, Tr, S, M> Factory<'a, T, Tr, S, M>
+where
+    T: Serialize,
+    Tr: Default,
+    S: Counter,
+    M: Template<'a, T>,
+{
+    /// Returns attributes of a struct built by [build](struct.Factory.html#method.build) as JSON.
+    ///
+    /// It advances the sequence number and applies `f` and `after_build` hooks like `build`.
+    ///
+    /// # Usage
+    /// ```rust
+    /// use serde::{Deserialize, Serialize};
+    /// use serde_json::json;
+    ///
+    /// #[derive(Serialize, Deserialize)]
+    /// struct Post {
+    ///     id: u64,
+    ///     title: String,
+    /// }
+    ///
+    /// beaver::define! {
+    ///     PostFactory (Post) {
+    ///         id -> |n| n,
+    ///         title -> |n| format!("post-{}", n),
+    ///     }
+    /// }
+    ///
+    /// let post_factory = PostFactory::new();
+    /// let attributes = post_factory.attributes_for(|post| post.title = "beaver".to_string());
+    /// assert_eq!(attributes, json!({"id": 1, "title": "beaver"}));
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the struct can't be built or serialized.
+    pub fn attributes_for<O>(&'a self, f: O) -> serde_json::Value
+    where
+        O: Fn(&mut T),
+    {
+        serde_json::to_value(self.build(f))
+            .unwrap_or_else(|err| panic!("{}", Error::Serialization(err)))
+    }
+
+    /// Returns attributes of a struct built by [build](struct.Factory.html#method.build) as a field map.
+    ///
+    /// # Panics
+    /// Panics if the struct can't be built or serialized, or it isn't serialized as a JSON object.
+    pub fn attributes_map_for<O>(&'a self, f: O) -> serde_json::Map<String, serde_json::Value>
+    where
+        O: Fn(&mut T),
+    {
+        match self.attributes_for(f) {
+            serde_json::Value::Object(map) => map,
+            value => panic!("Attributes must be a JSON object, but got `{}`.", value),
+        }
+    }
+
+    /// Returns a vector of attributes of structs built by [build_list](struct.Factory.html#method.build_list) as JSON.
+    ///
+    /// # Panics
+    /// Panics if the structs can't be built or serialized.
+    pub fn attributes_for_list<O>(&'a self, number: u64, f: O) -> Vec<serde_json::Value>
+    where
+        O: Fn(&mut T),
+    {
+        let mut list = vec![];
+        for _ in 0..number {
+            list.push(self.attributes_for(&f))
+        }
+        list
+    }
+}
+
+impl<'a, T, Tr, S, M> Factory<'a, T, Tr, S, M>
+where
+    T: Serialize + DeserializeOwned,
+    Tr: Default,
+    S: Counter,
+    M: Template<'a, T>,
+{
+    /// Builds a struct from [Factory](struct.Factory.html) with fields overridden by their paths.
+    ///
+    /// A path is a dotted path of fields such as `file.path`, and an index of a tuple struct or
+    /// a vector is a part of a path like `points.0`. Overrides are applied in the given order after the
+    /// generators, so a nested field overrides the value which a sub factory generated.
+    /// `f` and `after_build` hooks are applied after them.
+    ///
+    /// # Usage
+    /// ```rust
+    /// use serde::{Deserialize, Serialize};
+    /// use serde_json::json;
+    ///
+    /// #[derive(Serialize, Deserialize)]
+    /// struct File {
+    ///     id: u64,
+    ///     path: String,
+    /// }
+    ///
+    /// #[derive(Serialize, Deserialize)]
+    /// struct User {
+    ///     id: u64,
+    ///     file: File,
+    /// }
+    ///
+    /// beaver::define! {
+    ///     FileFactory (File) {
+    ///         id -> |n| n,
+    ///         path -> |n| format!("path/to/file-{}", n),
+    ///     }
+    /// }
+    ///
+    /// beaver::define! {
+    ///     UserFactory (User) {
+    ///         id -> |n| n,
+    ///         file -> |n| FileFactory::build(n),
+    ///     }
+    /// }
+    ///
+    /// let user_factory = UserFactory::new();
+    /// let user = user_factory.build_with_overrides(&[("file.path", json!("avatar.png"))], |_| {});
+    /// assert_eq!(user.file.id, 1);
+    /// assert_eq!(user.file.path, "avatar.png");
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if a path doesn't point to a field, or a value doesn't fit the field.
+    pub fn build_with_overrides<O>(&'a self, overrides: &[(&str, serde_json::Value)], f: O) -> T
+    where
+        O: Fn(&mut T),
+    {
+        self.try_build_with_overrides(overrides, f)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Builds a struct with fields overridden by their paths like
+    /// [build_with_overrides](struct.Factory.html#method.build_with_overrides),
+    /// returning an error instead of panicking.
+    pub fn try_build_with_overrides<O>(
+        &'a self,
+        overrides: &[(&str, serde_json::Value)],
+        f: O,
+    ) -> Result<T, Error>
+    where
+        O: Fn(&mut T),
+    {
+        let n = self.sequence.next().ok_or(Error::SequenceOverflow)?;
+        // `f` of `generate` can't return an error, so the error of overrides is kept aside.
+        let error = RefCell::new(None);
+        let model = self.generate(n, &self.gen_func, None, &[], &Tr::default(), |model| {
+            match apply_overrides(model, overrides) {
+                Ok(()) => f(model),
+                Err(err) => *error.borrow_mut() = Some(err),
+            }
+        })?;
+        match error.into_inner() {
+            Some(err) => Err(err),
+            None => Ok(model),
+        }
+    }
+}
+
+fn apply_overrides<T>(model: &mut T, overrides: &[(&str, serde_json::Value)]) -> Result<(), Error>
+where
+    T: Serialize + DeserializeOwned,
+{
+    if overrides.is_empty() {
+        return Ok(());
+    }
+    let mut json = serde_json::to_value(&*model).map_err(Error::Serialization)?;
+    for (path, value) in overrides {
+        let field = path
+            .split('.')
+            .try_fold(&mut json, |json, key| match json {
+                serde_json::Value::Object(map) => map.get_mut(key),
+                serde_json::Value::Array(values) => key
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(move |i| values.get_mut(i)),
+                _ => None,
+            })
+            .ok_or_else(|| Error::UnknownField(path.to_string()))?;
+        *field = value.clone();
+    }
+    *model = serde_path_to_error::deserialize(&json).map_err(|err| Error::Deserialization {
+        json: json.to_string(),
+        path: err.path().to_string(),
+        source: err.into_inner(),
+    })?;
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::factory::{new, sequence, sequence_a, to_alphabet};
+    use crate::error::Error;
+    use crate::factory::{
+        apply_overrides, cycle, cycle_weighted, new, sequence, sequence_a, to_alphabet,
+        try_sequence_a,
+    };
     use chrono::{NaiveDate, NaiveDateTime};
     use serde::{Deserialize, Serialize};
+    use serde_json::json;
+    use std::cell::Cell;
+    use std::rc::Rc;
 
     #[test]
     fn test_new() {
         #[derive(Serialize, Deserialize)]
         struct Post {
-            id: u16,
+            id: u64,
             title: String,
             approved: bool,
             file: File,
@@ -190,13 +1216,13 @@ mod tests {
 
         #[derive(Serialize, Deserialize, Debug)]
         struct File {
-            id: u16,
+            id: u64,
             path: String,
         }
 
         #[derive(Serialize, Deserialize, Debug)]
         struct Tag {
-            id: u16,
+            id: u64,
             name: String,
         }
 
@@ -226,7 +1252,10 @@ mod tests {
                     approved: true,
                     file: File::default(),
                     tags: vec![],
-                    created_at: NaiveDate::from_ymd(2020, 1, 1).and_hms(0, 0, 0),
+                    created_at: NaiveDate::from_ymd_opt(2020, 1, 1)
+                        .unwrap()
+                        .and_hms_opt(0, 0, 0)
+                        .unwrap(),
                 }
             }
         }
@@ -245,7 +1274,7 @@ mod tests {
 
         let post_factory = new(
             Post::default(),
-            Box::new(|post, n| {
+            Box::new(|post, n, _| {
                 post.id = n;
                 post.title = format!("post-{}", n);
                 post.approved = false;
@@ -267,7 +1296,10 @@ mod tests {
                         name: format!("tag-{}", n + 2),
                     },
                 ];
-                post.created_at = NaiveDate::from_ymd(2020, 1, 1).and_hms(0, 0, 0)
+                post.created_at = NaiveDate::from_ymd_opt(2020, 1, 1)
+                    .unwrap()
+                    .and_hms_opt(0, 0, 0)
+                    .unwrap()
             }),
         );
 
@@ -279,11 +1311,11 @@ mod tests {
 
         let mut post = Post::default();
         let f = post_factory.gen_func;
-        f(&mut post, 1);
+        f(&mut post, 1, &());
 
         assert_eq!(post.id, 1);
         assert_eq!(post.title, "post-1");
-        assert_eq!(post.approved, false);
+        assert!(!post.approved);
         assert_eq!(
             post.file,
             File {
@@ -310,8 +1342,32 @@ mod tests {
         );
         assert_eq!(
             post.created_at,
-            NaiveDate::from_ymd(2020, 1, 1).and_hms(0, 0, 0)
+            NaiveDate::from_ymd_opt(2020, 1, 1)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_new_with_non_thread_safe_function() {
+        #[derive(Serialize, Deserialize)]
+        struct Post {
+            id: u64,
+        }
+
+        let built = Rc::new(Cell::new(0));
+        let counter = built.clone();
+        let post_factory = new(
+            Post { id: 0 },
+            Box::new(move |post, n, _: &()| {
+                post.id = n;
+                counter.set(counter.get() + 1);
+            }),
         );
+
+        post_factory.build_list(2, |_| {});
+        assert_eq!(built.get(), 2);
     }
 
     #[test]
@@ -319,6 +1375,19 @@ mod tests {
         assert_eq!(sequence(2, 1), 2);
         assert_eq!(sequence(2, 2), 3);
         assert_eq!(sequence(2, 3), 4);
+        assert_eq!(sequence(1, 70_000), 70_000);
+    }
+
+    #[test]
+    fn test_sequence_with_overflow() {
+        let result = std::panic::catch_unwind(|| sequence(u64::MAX, 2));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "Sequence number is 0")]
+    fn test_sequence_with_zero() {
+        sequence(1, 0);
     }
 
     #[test]
@@ -352,6 +1421,58 @@ mod tests {
         assert!(result3.is_err());
     }
 
+    #[test]
+    fn test_try_sequence_a() {
+        assert_eq!(try_sequence_a("z", 2).unwrap(), "aa");
+        assert!(matches!(
+            try_sequence_a("panic", 1),
+            Err(Error::InvalidAlphabet(from)) if from == "panic"
+        ));
+        assert!(matches!(try_sequence_a("a", 0), Err(Error::ZeroSequence)));
+    }
+
+    #[test]
+    fn test_cycle() {
+        let roles = ["admin", "editor", "viewer"];
+        assert_eq!(cycle(&roles, 1), "admin");
+        assert_eq!(cycle(&roles, 2), "editor");
+        assert_eq!(cycle(&roles, 3), "viewer");
+        assert_eq!(cycle(&roles, 4), "admin");
+        assert_eq!(cycle(&[1u8], u64::MAX), 1);
+        assert_eq!(cycle(&[String::from("a"), String::from("b")], 6), "b");
+    }
+
+    #[test]
+    fn test_cycle_with_invalid_arguments() {
+        let result1 = std::panic::catch_unwind(|| cycle::<u64>(&[], 1));
+        let result2 = std::panic::catch_unwind(|| cycle(&[1], 0));
+        assert!(result1.is_err());
+        assert!(result2.is_err());
+    }
+
+    #[test]
+    fn test_cycle_weighted() {
+        let statuses = [("active", 2), ("pending", 0), ("banned", 1)];
+        let values: Vec<_> = (1..=7).map(|n| cycle_weighted(&statuses, n)).collect();
+        assert_eq!(
+            values,
+            ["active", "active", "banned", "active", "active", "banned", "active"]
+        );
+        assert_eq!(cycle_weighted(&[(1u8, u64::MAX)], u64::MAX), 1);
+    }
+
+    #[test]
+    fn test_cycle_weighted_with_invalid_arguments() {
+        let result1 = std::panic::catch_unwind(|| cycle_weighted::<u64>(&[], 1));
+        let result2 = std::panic::catch_unwind(|| cycle_weighted(&[(1, 0)], 1));
+        let result3 = std::panic::catch_unwind(|| cycle_weighted(&[(1, u64::MAX), (2, 1)], 1));
+        let result4 = std::panic::catch_unwind(|| cycle_weighted(&[(1, 1)], 0));
+        assert!(result1.is_err());
+        assert!(result2.is_err());
+        assert!(result3.is_err());
+        assert!(result4.is_err());
+    }
+
     #[test]
     fn test_to_alphabet() {
         assert_eq!(to_alphabet(0), "a");
@@ -361,4 +1482,46 @@ mod tests {
         assert_eq!(to_alphabet(701), "zz");
         assert_eq!(to_alphabet(702), "aaa")
     }
+
+    #[test]
+    fn test_apply_overrides() {
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        struct Point(i64, i64);
+
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        struct Shape {
+            name: String,
+            points: Vec<Point>,
+        }
+
+        let mut shape = Shape {
+            name: "line".to_string(),
+            points: vec![Point(0, 0), Point(1, 1)],
+        };
+        apply_overrides(
+            &mut shape,
+            &[("name", json!("segment")), ("points.1.0", json!(2))],
+        )
+        .unwrap();
+        assert_eq!(
+            shape,
+            Shape {
+                name: "segment".to_string(),
+                points: vec![Point(0, 0), Point(2, 1)],
+            }
+        );
+
+        match apply_overrides(&mut shape, &[("points.2.0", json!(1))]) {
+            Err(Error::UnknownField(path)) => assert_eq!(path, "points.2.0"),
+            result => panic!("unexpected result: {:?}", result),
+        }
+        match apply_overrides(&mut shape, &[("name.first", json!(1))]) {
+            Err(Error::UnknownField(path)) => assert_eq!(path, "name.first"),
+            result => panic!("unexpected result: {:?}", result),
+        }
+        match apply_overrides(&mut shape, &[("points.0.1", json!("one"))]) {
+            Err(Error::Deserialization { path, .. }) => assert_eq!(path, "points[0][1]"),
+            result => panic!("unexpected result: {:?}", result),
+        }
+    }
 }
@@ -0,0 +1,406 @@
+//! Deterministic fake data for factory definitions.
+//!
+//! Every generator takes a sequence number, so the same `n` always returns the same value and
+//! test runs are reproducible. Values include spaces, apostrophes and non-ASCII letters, which
+//! `format!("user-{}", n)` never exercises.
+//!
+//! This module is available if you enable `fake` feature.
+//!
+//! # Usage
+//! ```rust
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct User {
+//!     id: u64,
+//!     name: String,
+//!     email: String,
+//!     bio: String,
+//! }
+//!
+//! beaver::define! {
+//!     UserFactory (User) {
+//!         id -> |n| n,
+//!         name -> |n| beaver::fake::name(n),
+//!         email -> |n| beaver::fake::email(n),
+//!         // Another seed returns another set of values.
+//!         bio -> |n| beaver::fake::Faker::new(42).paragraph(n),
+//!     }
+//! }
+//!
+//! let user_factory = UserFactory::new();
+//! let user = user_factory.build(|_| {});
+//! assert_eq!(user.name, beaver::fake::name(1));
+//! assert!(user.email.contains("1@example."));
+//! ```
+
+const FIRST_NAMES: &[&str] = &[
+    "Alice",
+    "Björn",
+    "Chloé",
+    "Dmitri",
+    "Émilie",
+    "François",
+    "Hiroshi",
+    "Ingrid",
+    "Jean-Luc",
+    "José",
+    "Łukasz",
+    "Mary Jane",
+    "Mei",
+    "Ngozi",
+    "Renée",
+    "Siobhán",
+    "Søren",
+    "Zoë",
+];
+
+const LAST_NAMES: &[&str] = &[
+    "Brontë",
+    "D'Angelo",
+    "de la Cruz",
+    "García",
+    "Ishikawa",
+    "Kowalski",
+    "Müller",
+    "Nguyễn",
+    "O'Brien",
+    "Okafor",
+    "Østergaard",
+    "Smith",
+    "van der Berg",
+    "Wójcik",
+];
+
+const COMPANY_SUFFIXES: &[&str] = &["Inc.", "LLC", "GmbH", "S.A.", "& Sons", "K.K."];
+
+const EMAIL_DOMAINS: &[&str] = &["example.com", "example.net", "example.org"];
+
+const STREETS: &[&str] = &[
+    "Baker Street",
+    "Calle de Alcalá",
+    "Königstraße",
+    "Main Street",
+    "O'Connell Street",
+    "Rue de l'Église",
+    "Via dell'Amore",
+];
+
+const CITIES: &[&str] = &[
+    "Coeur d'Alene",
+    "Kraków",
+    "Montréal",
+    "Reykjavík",
+    "São Paulo",
+    "Springfield",
+    "Zürich",
+];
+
+const LOREM: &[&str] = &[
+    "lorem",
+    "ipsum",
+    "dolor",
+    "sit",
+    "amet",
+    "consectetur",
+    "adipiscing",
+    "elit",
+    "sed",
+    "do",
+    "eiusmod",
+    "tempor",
+    "incididunt",
+    "ut",
+    "labore",
+    "et",
+    "dolore",
+    "magna",
+    "aliqua",
+];
+
+/// A generator of fake data with a seed.
+///
+/// The free functions of this module use the default seed, 0.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Faker {
+    seed: u64,
+}
+
+impl Faker {
+    /// Creates a generator with a seed.
+    pub const fn new(seed: u64) -> Self {
+        Faker { seed }
+    }
+
+    /// Returns a first name such as "Siobhán".
+    pub fn first_name(&self, n: u64) -> String {
+        self.stream(n, 1).pick(FIRST_NAMES).to_string()
+    }
+
+    /// Returns a last name such as "O'Brien".
+    pub fn last_name(&self, n: u64) -> String {
+        self.stream(n, 2).pick(LAST_NAMES).to_string()
+    }
+
+    /// Returns a full name such as "Siobhán O'Brien".
+    pub fn name(&self, n: u64) -> String {
+        format!("{} {}", self.first_name(n), self.last_name(n))
+    }
+
+    /// Returns an email address derived from [name](#method.name), such as
+    /// "siobhan.obrien1@example.com".
+    ///
+    /// The address contains `n`, so it is unique within a factory, and uses domains reserved
+    /// for documentation.
+    pub fn email(&self, n: u64) -> String {
+        format!(
+            "{}.{}{}@{}",
+            to_ascii_local_part(&self.first_name(n)),
+            to_ascii_local_part(&self.last_name(n)),
+            n,
+            self.stream(n, 3).pick(EMAIL_DOMAINS)
+        )
+    }
+
+    /// Returns a company name such as "Müller & O'Brien GmbH".
+    pub fn company(&self, n: u64) -> String {
+        let mut stream = self.stream(n, 4);
+        let name = stream.pick(LAST_NAMES);
+        match stream.next() % 3 {
+            0 => format!("{} {}", name, stream.pick(COMPANY_SUFFIXES)),
+            1 => format!("{} & {}", name, stream.pick(LAST_NAMES)),
+            _ => format!(
+                "{} & {} {}",
+                name,
+                stream.pick(LAST_NAMES),
+                stream.pick(COMPANY_SUFFIXES)
+            ),
+        }
+    }
+
+    /// Returns `count` lorem ipsum words.
+    pub fn words(&self, n: u64, count: usize) -> Vec<String> {
+        let mut stream = self.stream(n, 5);
+        (0..count).map(|_| stream.pick(LOREM).to_string()).collect()
+    }
+
+    /// Returns a lorem ipsum sentence of 4 to 10 words.
+    pub fn sentence(&self, n: u64) -> String {
+        lorem_sentence(&mut self.stream(n, 6))
+    }
+
+    /// Returns a lorem ipsum paragraph of 3 to 5 sentences.
+    pub fn paragraph(&self, n: u64) -> String {
+        let mut stream = self.stream(n, 7);
+        let count = 3 + stream.next() % 3;
+        (0..count)
+            .map(|_| lorem_sentence(&mut stream))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Returns a street address such as "221 Rue de l'Église".
+    pub fn street_address(&self, n: u64) -> String {
+        let mut stream = self.stream(n, 8);
+        format!("{} {}", 1 + stream.next() % 9999, stream.pick(STREETS))
+    }
+
+    /// Returns a city such as "São Paulo".
+    pub fn city(&self, n: u64) -> String {
+        self.stream(n, 9).pick(CITIES).to_string()
+    }
+
+    /// Returns a full address such as "221 Rue de l'Église, São Paulo 01310".
+    pub fn address(&self, n: u64) -> String {
+        format!(
+            "{}, {} {:05}",
+            self.street_address(n),
+            self.city(n),
+            self.stream(n, 10).next() % 100_000
+        )
+    }
+
+    /// Returns a phone number in the fictional 555-01XX range, such as "+1 (312) 555-0142".
+    pub fn phone(&self, n: u64) -> String {
+        let mut stream = self.stream(n, 11);
+        format!(
+            "+1 ({}) 555-01{:02}",
+            201 + stream.next() % 799,
+            stream.next() % 100
+        )
+    }
+
+    fn stream(&self, n: u64, salt: u64) -> Stream {
+        Stream(
+            self.seed
+                ^ salt.wrapping_mul(0x9E37_79B9_7F4A_7C15)
+                ^ n.wrapping_mul(0xBF58_476D_1CE4_E5B9),
+        )
+    }
+}
+
+/// Returns a first name with the default seed. See [Faker::first_name](struct.Faker.html#method.first_name).
+pub fn first_name(n: u64) -> String {
+    Faker::default().first_name(n)
+}
+
+/// Returns a last name with the default seed. See [Faker::last_name](struct.Faker.html#method.last_name).
+pub fn last_name(n: u64) -> String {
+    Faker::default().last_name(n)
+}
+
+/// Returns a full name with the default seed. See [Faker::name](struct.Faker.html#method.name).
+pub fn name(n: u64) -> String {
+    Faker::default().name(n)
+}
+
+/// Returns an email address with the default seed. See [Faker::email](struct.Faker.html#method.email).
+pub fn email(n: u64) -> String {
+    Faker::default().email(n)
+}
+
+/// Returns a company name with the default seed. See [Faker::company](struct.Faker.html#method.company).
+pub fn company(n: u64) -> String {
+    Faker::default().company(n)
+}
+
+/// Returns lorem ipsum words with the default seed. See [Faker::words](struct.Faker.html#method.words).
+pub fn words(n: u64, count: usize) -> Vec<String> {
+    Faker::default().words(n, count)
+}
+
+/// Returns a lorem ipsum sentence with the default seed. See [Faker::sentence](struct.Faker.html#method.sentence).
+pub fn sentence(n: u64) -> String {
+    Faker::default().sentence(n)
+}
+
+/// Returns a lorem ipsum paragraph with the default seed. See [Faker::paragraph](struct.Faker.html#method.paragraph).
+pub fn paragraph(n: u64) -> String {
+    Faker::default().paragraph(n)
+}
+
+/// Returns a street address with the default seed. See [Faker::street_address](struct.Faker.html#method.street_address).
+pub fn street_address(n: u64) -> String {
+    Faker::default().street_address(n)
+}
+
+/// Returns a city with the default seed. See [Faker::city](struct.Faker.html#method.city).
+pub fn city(n: u64) -> String {
+    Faker::default().city(n)
+}
+
+/// Returns a full address with the default seed. See [Faker::address](struct.Faker.html#method.address).
+pub fn address(n: u64) -> String {
+    Faker::default().address(n)
+}
+
+/// Returns a phone number with the default seed. See [Faker::phone](struct.Faker.html#method.phone).
+pub fn phone(n: u64) -> String {
+    Faker::default().phone(n)
+}
+
+/// A splitmix64 stream, which is small and good enough to pick values from lists.
+struct Stream(u64);
+
+impl Stream {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    fn pick(&mut self, values: &[&'static str]) -> &'static str {
+        values[(self.next() % values.len() as u64) as usize]
+    }
+}
+
+fn lorem_sentence(stream: &mut Stream) -> String {
+    let count = 4 + stream.next() % 7;
+    let words: Vec<_> = (0..count).map(|_| stream.pick(LOREM)).collect();
+    let sentence = words.join(" ");
+    let mut chars = sentence.chars();
+    match chars.next() {
+        Some(first) => format!("{}{}.", first.to_uppercase(), chars.as_str()),
+        None => sentence,
+    }
+}
+
+/// Lowercases a name and drops or transliterates the characters which are not allowed
+/// in the local part of an email address.
+fn to_ascii_local_part(name: &str) -> String {
+    name.chars()
+        .flat_map(char::to_lowercase)
+        .filter_map(|c| match c {
+            'a'..='z' | '0'..='9' => Some(c),
+            'á' | 'à' | 'â' | 'ä' | 'ã' | 'å' => Some('a'),
+            'ç' => Some('c'),
+            'é' | 'è' | 'ê' | 'ë' | 'ễ' => Some('e'),
+            'í' | 'ì' | 'î' | 'ï' => Some('i'),
+            'ł' => Some('l'),
+            'ñ' => Some('n'),
+            'ó' | 'ò' | 'ô' | 'ö' | 'õ' | 'ø' => Some('o'),
+            'ú' | 'ù' | 'û' | 'ü' => Some('u'),
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fake::{self, to_ascii_local_part, Faker};
+
+    #[test]
+    fn test_deterministic() {
+        for n in 1..50 {
+            assert_eq!(fake::name(n), fake::name(n));
+            assert_eq!(fake::email(n), Faker::new(0).email(n));
+            assert_eq!(Faker::new(7).paragraph(n), Faker::new(7).paragraph(n));
+        }
+        assert!((1..50).any(|n| Faker::new(1).name(n) != Faker::new(2).name(n)));
+    }
+
+    #[test]
+    fn test_realistic_data() {
+        let names: Vec<_> = (1..200).map(fake::name).collect();
+        assert!(names.iter().any(|name| name.contains('\'')));
+        assert!(names.iter().any(|name| !name.is_ascii()));
+        assert!(names.iter().all(|name| name.contains(' ')));
+        assert!((1..200).any(|n| !fake::address(n).is_ascii()));
+    }
+
+    #[test]
+    fn test_email() {
+        for n in 1..200 {
+            let email = fake::email(n);
+            assert!(email.is_ascii(), "{}", email);
+            assert!(!email.contains(' ') && !email.contains('\''), "{}", email);
+            assert!(email.contains(&format!("{}@example.", n)), "{}", email);
+        }
+    }
+
+    #[test]
+    fn test_lorem() {
+        assert_eq!(fake::words(1, 3).len(), 3);
+        let sentence = fake::sentence(1);
+        assert!(sentence.ends_with('.'));
+        assert!(sentence.starts_with(char::is_uppercase));
+        assert!(fake::paragraph(1).matches('.').count() >= 3);
+    }
+
+    #[test]
+    fn test_phone() {
+        let phone = fake::phone(1);
+        assert!(phone.starts_with("+1 ("));
+        assert!(phone.contains(") 555-01"));
+    }
+
+    #[test]
+    fn test_to_ascii_local_part() {
+        assert_eq!(to_ascii_local_part("Siobhán"), "siobhan");
+        assert_eq!(to_ascii_local_part("O'Brien"), "obrien");
+        assert_eq!(to_ascii_local_part("van der Berg"), "vanderberg");
+        assert_eq!(to_ascii_local_part("Østergaard"), "ostergaard");
+    }
+}
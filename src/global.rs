@@ -0,0 +1,119 @@
+use crate::counter::Counter;
+use lazy_static::lazy_static;
+use std::sync::atomic::AtomicU64;
+use std::sync::{Arc, Mutex, OnceLock};
+
+lazy_static! {
+    /// Counters which live until the end of the program, with their first sequence numbers.
+    static ref COUNTERS: Mutex<Vec<(&'static AtomicU64, u64)>> = Mutex::new(vec![]);
+}
+
+/// Sequence numbers of the process-wide counters taken by [snapshot](fn.snapshot.html).
+pub struct Snapshot {
+    counters: Vec<(&'static AtomicU64, u64)>,
+}
+
+pub(crate) fn register_counter(counter: &'static AtomicU64, first: u64) {
+    COUNTERS
+        .lock()
+        .unwrap_or_else(|err| err.into_inner())
+        .push((counter, first));
+}
+
+/// Returns the process-wide counter of a factory definition stored in `cell`.
+///
+/// The counter is created and registered on first use, so that `reset_all` and `restore` can reach it.
+#[doc(hidden)]
+pub fn shared_counter(cell: &'static OnceLock<Arc<AtomicU64>>) -> Arc<AtomicU64> {
+    let mut created = false;
+    let counter = cell.get_or_init(|| {
+        created = true;
+        Arc::new(AtomicU64::new(1))
+    });
+    if created {
+        register_counter(counter, 1);
+    }
+    counter.clone()
+}
+
+/// Forgets a counter, so that it is no longer reset or restored.
+pub(crate) fn unregister_counter(counter: &'static AtomicU64) {
+    COUNTERS
+        .lock()
+        .unwrap_or_else(|err| err.into_inner())
+        .retain(|(registered, _)| !std::ptr::eq(*registered, counter));
+}
+
+#[cfg(test)]
+pub(crate) fn is_registered(counter: &AtomicU64) -> bool {
+    COUNTERS
+        .lock()
+        .unwrap_or_else(|err| err.into_inner())
+        .iter()
+        .any(|(registered, _)| std::ptr::eq(*registered, counter))
+}
+
+/// Resets every process-wide counter to its first sequence number.
+///
+/// Process-wide counters are the ones of named sequences defined by [sequence!](macro.sequence.html)
+/// and factories registered with [register](fn.register.html).
+///
+/// # Usage
+/// ```rust
+/// beaver::sequence!(email, |n| format!("person{}@example.com", n));
+///
+/// assert_eq!(beaver::next(email), "person1@example.com");
+/// beaver::reset_all();
+/// assert_eq!(beaver::next(email), "person1@example.com");
+/// ```
+pub fn reset_all() {
+    for (counter, first) in COUNTERS
+        .lock()
+        .unwrap_or_else(|err| err.into_inner())
+        .iter()
+    {
+        counter.set(*first);
+    }
+}
+
+/// Takes sequence numbers of every process-wide counter, so that [restore](fn.restore.html)
+/// can put them back.
+///
+/// # Usage
+/// ```rust
+/// beaver::sequence!(email, |n| format!("person{}@example.com", n));
+///
+/// let snapshot = beaver::snapshot();
+/// assert_eq!(beaver::next(email), "person1@example.com");
+/// assert_eq!(beaver::next(email), "person2@example.com");
+/// beaver::restore(&snapshot);
+/// assert_eq!(beaver::next(email), "person1@example.com");
+/// ```
+pub fn snapshot() -> Snapshot {
+    Snapshot {
+        counters: COUNTERS
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .iter()
+            .map(|(counter, _)| (*counter, counter.get()))
+            .collect(),
+    }
+}
+
+/// Puts sequence numbers of every process-wide counter back to a [snapshot](fn.snapshot.html).
+///
+/// Counters which started counting after the snapshot are reset to their first sequence numbers.
+pub fn restore(snapshot: &Snapshot) {
+    for (counter, first) in COUNTERS
+        .lock()
+        .unwrap_or_else(|err| err.into_inner())
+        .iter()
+    {
+        let n = snapshot
+            .counters
+            .iter()
+            .find(|(taken, _)| std::ptr::eq(*taken, *counter))
+            .map_or(*first, |(_, n)| *n);
+        counter.set(n);
+    }
+}
@@ -27,7 +27,7 @@
 //! // Your struct needs both of `Serialize` and `Deserialize`.
 //! #[derive(Serialize, Deserialize)]
 //! struct Post {
-//!     id: u16,
+//!     id: u64,
 //!     title: String,
 //!     approved: bool,
 //! }
@@ -48,6 +48,13 @@
 //! You can use the following functions in factory definition.
 //! - [sequence](factory/fn.sequence.html): If you want to use a sequence number, you can use this function.
 //! - [sequence_a](factory/fn.sequence_a.html): If you want to use a sequence letter, you can use this function.
+//! - [cycle](factory/fn.cycle.html): If you want to rotate through a list of values, you can use this function.
+//!   [cycle_weighted](factory/fn.cycle_weighted.html) repeats each value as many times as its weight.
+//! - [next](fn.next.html): If you want to use a sequence shared by factories, you can define it by [sequence!](macro.sequence.html) and use this function.
+//! - [seed](fn.seed.html): Random values from `rng()` of a field generator are generated with this seed. You can override it with `BEAVER_SEED` environment variable or [set_seed](fn.set_seed.html). It is available if you enable `random` feature.
+//!
+//! If you enable `derive` feature, you can also define a factory with `#[derive(beaver::Factory)]`.
+//! For more information, please see [Factory](derive.Factory.html).
 //!
 //! ### Build structs
 //! ```
@@ -55,7 +62,7 @@
 //!
 //! #[derive(Serialize, Deserialize, Debug)]
 //! struct Post {
-//!     id: u16,
+//!     id: u64,
 //!     title: String,
 //!     approved: bool,
 //! }
@@ -74,32 +81,66 @@
 //!     }
 //! }
 //!
-//! #[cfg(test)]
-//! mod tests {
-//!     #[test]
-//!     fn test_something() {
-//!         use crate::factory::PostFactory;
-//!
-//!         let post_factory = PostFactory::new();
-//!         let post1 = post_factory.build(|_| {});
-//!         let post2 = post_factory.build(|_| {});
-//!         // override attributes of a factory
-//!         let post3 = post_factory.build(|post| {
-//!             post.id = 1024;
-//!             post.title = "foo bar".to_string()
-//!         });
-//!
-//!         assert_eq!(post1.something(), "post-1");
-//!         assert_eq!(post2.something(), "post-2");
-//!         assert_eq!(post3.something(), "foo bar");
-//!     }
-//! }
+//! let post_factory = PostFactory::new();
+//! let post1 = post_factory.build(|_| {});
+//! let post2 = post_factory.build(|_| {});
+//! // override attributes of a factory
+//! let post3 = post_factory.build(|post| {
+//!     post.id = 1024;
+//!     post.title = "foo bar".to_string()
+//! });
+//!
+//! assert_eq!(post1.something(), "1");
+//! assert_eq!(post2.something(), "2");
+//! assert_eq!(post3.something(), "foo bar");
 //! ```
 //!
 
+mod counter;
+mod error;
 mod factory;
+#[cfg(feature = "fake")]
+pub mod fake;
+mod global;
 mod macros;
+mod persister;
+#[cfg(feature = "random")]
+mod random;
+mod registry;
+mod sequence;
+mod stub;
+mod template;
 mod variable;
 
-pub use factory::Factory;
-pub use factory::{new, sequence, sequence_a};
+#[cfg(feature = "derive")]
+pub use beaver_derive::Factory;
+pub use counter::Counter;
+pub use error::{CreateError, Error};
+pub use factory::{cycle, cycle_weighted, sequence, sequence_a, try_sequence_a};
+pub use factory::{new, new_with_counter, try_new, try_new_with_counter};
+pub use factory::{Definition, Factory, SyncFactory};
+#[doc(hidden)]
+pub use factory::{ModelScope, Scope};
+#[doc(hidden)]
+pub use global::shared_counter;
+pub use global::{reset_all, restore, snapshot, Snapshot};
+pub use persister::{HashMapPersister, Persister};
+#[cfg(feature = "random")]
+pub use rand;
+#[cfg(feature = "random")]
+pub use random::{print_seed_on_panic, seed, set_seed, SeededRng, SEED_ENV};
+#[cfg(feature = "random")]
+#[doc(hidden)]
+pub use random::{rng, ContextRng};
+pub use registry::{build, build_value, register, registered, try_build, try_build_value};
+#[doc(hidden)]
+pub use sequence::peek;
+pub use sequence::{next, Sequence};
+pub use stub::{Stub, STUB_ID_START};
+pub use template::{Cloned, Template};
+
+/// A field generator's context has no random number generator without `random` feature.
+#[cfg(not(feature = "random"))]
+#[doc(hidden)]
+#[derive(Default)]
+pub struct ContextRng;
@@ -6,7 +6,7 @@
 ///
 /// #[derive(Serialize, Deserialize)]
 /// struct Post {
-///     id: u16,
+///     id: u64,
 ///     title: String,
 ///     approved: bool,
 /// }
@@ -20,19 +20,44 @@
 /// }
 /// ```
 ///
+/// The struct can be referred by a path or a type alias, so a factory doesn't need to import it.
+/// ```rust
+/// mod models {
+///     use serde::{Deserialize, Serialize};
+///
+///     #[derive(Serialize, Deserialize)]
+///     pub struct Post {
+///         pub id: u64,
+///     }
+/// }
+///
+/// mod factories {
+///     beaver::define! {
+///         pub PostFactory (crate::models::Post) {
+///             id -> |n| n,
+///         }
+///     }
+/// }
+///
+/// fn main() {
+///     let post = factories::PostFactory::new().build(|_| {});
+///     assert_eq!(post.id, 1);
+/// }
+/// ```
+///
 /// If you want to use a sub factory, you can use `build(n)` like the following. ([Example](https://github.com/TaKO8Ki/beaver/blob/master/examples/sub_factory.rs))
 /// ```rust
 /// use serde::{Deserialize, Serialize};
 ///
 /// #[derive(Serialize, Deserialize)]
 /// struct File {
-///     id: u16,
+///     id: u64,
 ///     path: String,
 /// }
 ///
 /// #[derive(Serialize, Deserialize)]
 /// struct User {
-///     id: u16,
+///     id: u64,
 ///     name: String,
 ///     file: File,
 /// }
@@ -53,13 +78,52 @@
 /// }
 /// ```
 ///
+/// A dotted path such as `file.path` overrides a field of the value which a sub factory generated.
+/// Fields are assigned in the order of the body, so put the path after the field it belongs to.
+/// A struct can also be built with overrides by paths at runtime with
+/// [build_with_overrides](struct.Factory.html#method.build_with_overrides).
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct File {
+///     id: u64,
+///     path: String,
+/// }
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct User {
+///     id: u64,
+///     file: File,
+/// }
+///
+/// beaver::define! {
+///     FileFactory (File) {
+///         id -> |n| n,
+///         path -> |n| format!("path/to/file-{}", n),
+///     }
+/// }
+///
+/// beaver::define! {
+///     UserFactory (User) {
+///         id -> |n| n,
+///         file -> |n| FileFactory::build(n),
+///         file.path -> |n| format!("avatar-{}.png", n),
+///     }
+/// }
+///
+/// let user = UserFactory::new().build(|_| {});
+/// assert_eq!(user.file.id, 1);
+/// assert_eq!(user.file.path, "avatar-1.png");
+/// ```
+///
 /// If you want to use a vector of sub factories, you can use `build_list(number, n)` like the following. ([Example](https://github.com/TaKO8Ki/beaver/blob/master/examples/sub_factory_vector.rs))
 /// ```rust
 /// use serde::{Deserialize, Serialize};
 ///
 /// #[derive(Serialize, Deserialize)]
 /// struct Post {
-///     id: u16,
+///     id: u64,
 ///     title: String,
 ///     approved: bool,
 ///     tags: Vec<Tag>,
@@ -67,7 +131,7 @@
 ///
 /// #[derive(Serialize, Deserialize)]
 /// struct Tag {
-///     id: u16,
+///     id: u64,
 ///     name: String,
 /// }
 ///
@@ -95,7 +159,7 @@
 /// #[derive(Serialize, Deserialize)]
 /// // `Post` needs to be public.
 /// pub struct Post {
-///     id: u16,
+///     id: u64,
 ///     title: String,
 ///     approved: bool,
 /// }
@@ -109,7 +173,405 @@
 ///     }
 /// }
 /// ```
-
+///
+/// If you want to define variants of a factory, you can use traits like the following.
+/// Traits are applied by [build_with_traits](struct.Factory.html#method.build_with_traits)
+/// and [build_list_with_traits](struct.Factory.html#method.build_list_with_traits).
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Post {
+///     id: u64,
+///     title: String,
+///     approved: bool,
+/// }
+///
+/// beaver::define! {
+///     PostFactory (Post) {
+///         id -> |n| n,
+///         title -> |n| format!("post-{}", n),
+///         approved -> |_| false,
+///         trait approved {
+///             approved -> |_| true,
+///         }
+///         trait untitled {
+///             title -> |_| "".to_string(),
+///         }
+///     }
+/// }
+///
+/// let post_factory = PostFactory::new();
+/// let post = post_factory.build_with_traits(&["approved", "untitled"], |_| {});
+/// assert!(post.approved);
+/// assert_eq!(post.title, "");
+/// ```
+///
+/// If you want to use values which steer generation but are not fields of the struct,
+/// you can use transient attributes like the following.
+/// `transient [struct name] { [name]: [type] = [default value], ... }` defines a struct which has the transient attributes,
+/// and field generators can read them through the second argument.
+/// Unlike factory_bot's `transient { tags_count -> 3 }`, the struct needs a name and the types of its fields,
+/// because [build_with_transient](struct.Factory.html#method.build_with_transient) overrides them through `&mut` the struct
+/// and a macro can't infer types from default values.
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Post {
+///     id: u64,
+///     title: String,
+///     tags: Vec<Tag>,
+/// }
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Tag {
+///     id: u64,
+///     name: String,
+/// }
+///
+/// beaver::define! {
+///     PostFactory (Post) {
+///         transient PostTransient {
+///             tags_count: u64 = 3,
+///             upcased: bool = false,
+///         }
+///         id -> |n| n,
+///         title -> |n, t| if t.upcased { format!("POST-{}", n) } else { format!("post-{}", n) },
+///         tags -> |n, t| TagFactory::build_list(t.tags_count, n),
+///     }
+/// }
+///
+/// beaver::define! {
+///     TagFactory (Tag) {
+///         id -> |n| n,
+///         name -> |n| format!("tag-{}", n),
+///     }
+/// }
+///
+/// let post_factory = PostFactory::new();
+/// let post = post_factory.build_with_transient(|t| t.tags_count = 5, |_| {});
+/// assert_eq!(post.tags.len(), 5);
+/// ```
+///
+/// If you want to generate a field from other fields, you can use the second argument of
+/// a field generator like the following. It has references to the fields defined before the field,
+/// so fields are generated in the defined order and referring to a later field is a compile error.
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Post {
+///     id: u64,
+///     title: String,
+///     slug: String,
+/// }
+///
+/// beaver::define! {
+///     PostFactory (Post) {
+///         id -> |n| n,
+///         title -> |n| format!("Post {}", n),
+///         slug -> |_, post| post.title.to_lowercase().replace(' ', "-"),
+///     }
+/// }
+///
+/// let post_factory = PostFactory::new();
+/// let post = post_factory.build(|_| {});
+/// assert_eq!(post.slug, "post-1");
+/// ```
+///
+/// If you want a random but reproducible value, you can enable `random` feature and use `rng()` of the second argument.
+/// It is seeded from the global [seed](fn.seed.html), the factory name, the field name and
+/// the sequence number, so a struct gets the same values as long as the seed is the same.
+/// The seed is read from `BEAVER_SEED` environment variable or chosen randomly,
+/// and [print_seed_on_panic](fn.print_seed_on_panic.html) prints it when a test fails.
+/// ([SeededRng](type.SeededRng.html))
+///
+/// By default, a factory keeps a struct as JSON, so the struct needs both of `Serialize` and `Deserialize`.
+/// If you put `clone` after the struct name, the factory clones the struct instead and it only needs `Clone`.
+/// ([Cloned](struct.Cloned.html))
+/// ```rust
+/// #[derive(Clone)]
+/// struct Post {
+///     id: u64,
+///     title: String,
+/// }
+///
+/// beaver::define! {
+///     PostFactory (Post) clone {
+///         id -> |n| n,
+///         title -> |n| format!("post-{}", n),
+///     }
+/// }
+/// ```
+///
+/// If you want to post-process a built struct, you can use `after_build |[struct], [sequence number]| { ... }`
+/// like the following. A struct is built in this order:
+///
+/// 1. Fields are generated in the defined order.
+/// 2. Traits are applied in the given order.
+/// 3. The closure passed to `build` overrides fields.
+/// 4. `after_build` hooks run in the defined order.
+///
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Post {
+///     id: u64,
+///     title: String,
+///     checksum: usize,
+/// }
+///
+/// beaver::define! {
+///     PostFactory (Post) {
+///         id -> |n| n,
+///         title -> |n| format!("post-{}", n),
+///         checksum -> |_| 0,
+///         after_build |post, _| {
+///             post.checksum = post.title.len();
+///         }
+///     }
+/// }
+///
+/// let post_factory = PostFactory::new();
+/// let post = post_factory.build(|post| post.title = "beaver".to_string());
+/// assert_eq!(post.checksum, 6);
+/// ```
+///
+/// [create](struct.Factory.html#method.create) also runs `before_create` hooks before saving a struct
+/// and `after_create` hooks on the saved struct.
+///
+/// If you want to build a struct which looks persisted, you can use
+/// [build_stubbed](struct.Factory.html#method.build_stubbed). It sets `id` to a stub id and
+/// `created_at` and `updated_at` to the current time if the struct has them.
+/// A `stub` block overrides them and stubs other fields. Fields in the block are generated with
+/// a stub id instead of the sequence number, and a field without a generator is set by [Stub](trait.Stub.html).
+/// Stub ids start from 1001, or from the value of `stub(from)`.
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+/// use std::time::SystemTime;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Post {
+///     id: u64,
+///     title: String,
+///     created_at: Option<SystemTime>,
+///     updated_at: Option<SystemTime>,
+/// }
+///
+/// beaver::define! {
+///     PostFactory (Post) {
+///         id -> |_| 0,
+///         title -> |n| format!("post-{}", n),
+///         created_at -> |_| None,
+///         updated_at -> |_| None,
+///         stub (500) {
+///             title -> |id| format!("stubbed-post-{}", id),
+///             updated_at -> |_, post| *post.created_at,
+///         }
+///     }
+/// }
+///
+/// let post_factory = PostFactory::new();
+/// let post = post_factory.build_stubbed(|_| {});
+/// assert_eq!(post.id, 500);
+/// assert_eq!(post.title, "stubbed-post-500");
+/// assert_eq!(post.created_at, post.updated_at);
+/// ```
+///
+/// If you want a factory of a generic struct, you can put generic parameters after the factory name
+/// and a where clause before the body. The factory type and its `new`, `build` and `build_list` get the parameters.
+/// A child factory of a generic factory also needs `'static` bounds on its type parameters.
+/// ```rust
+/// use serde::de::DeserializeOwned;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Page<T> {
+///     number: u64,
+///     items: Vec<T>,
+/// }
+///
+/// beaver::define! {
+///     PageFactory<T> (Page<T>) where T: Serialize + DeserializeOwned + Default {
+///         number -> |n| n,
+///         items -> |_| vec![T::default()],
+///     }
+/// }
+///
+/// let page_factory = PageFactory::<String>::new();
+/// let page = page_factory.build(|_| {});
+/// assert_eq!(page.items, vec![String::new()]);
+/// ```
+///
+/// A struct literal needs every field, so a factory lists all fields of a struct by default.
+/// If you put `..Default` at the end of the body, unlisted fields are filled with `Default::default()`
+/// of the struct. `..default_model(expr)` fills them with fields of `expr` instead.
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize, Default)]
+/// struct Settings {
+///     id: u64,
+///     theme: String,
+///     font_size: u32,
+///     notifications: bool,
+/// }
+///
+/// beaver::define! {
+///     SettingsFactory (Settings) {
+///         id -> |n| n,
+///         ..Default
+///     }
+/// }
+///
+/// beaver::define! {
+///     DarkSettingsFactory (Settings) {
+///         id -> |n| n,
+///         ..default_model(Settings {
+///             theme: "dark".to_string(),
+///             ..Default::default()
+///         })
+///     }
+/// }
+///
+/// let settings = SettingsFactory::new().build(|_| {});
+/// assert_eq!(settings.font_size, 0);
+/// let settings = DarkSettingsFactory::new().build(|_| {});
+/// assert_eq!(settings.theme, "dark");
+/// ```
+///
+/// Fields of a tuple struct are defined by their indexes, like `0 -> |n| ...`.
+/// A generator of a positional field can't refer to other fields from its context.
+/// A newtype has a shorthand, `[factory name] ([struct name]) -> [generator]`.
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize, Debug, PartialEq)]
+/// struct UserId(u64);
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Point(i64, i64);
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct User {
+///     id: UserId,
+///     name: String,
+/// }
+///
+/// beaver::define! {
+///     UserIdFactory (UserId) -> |n| n
+/// }
+///
+/// beaver::define! {
+///     PointFactory (Point) {
+///         0 -> |n| n as i64,
+///         1 -> |_| 0,
+///     }
+/// }
+///
+/// beaver::define! {
+///     UserFactory (User) {
+///         id -> |n| UserIdFactory::build(n),
+///         name -> |n| format!("user-{}", n),
+///     }
+/// }
+///
+/// let point = PointFactory::new().build(|point| point.1 = 5);
+/// assert_eq!((point.0, point.1), (1, 5));
+/// let user = UserFactory::new().build(|_| {});
+/// assert_eq!(user.id, UserId(1));
+/// ```
+///
+/// A factory of an enum has a block of fields for each variant, `[variant name] { ... }`.
+/// It builds the variants in turn, and [build_variant](struct.Factory.html#method.build_variant)
+/// builds a specific variant. Variants don't support traits, hooks, stubs or transient attributes.
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize, Debug, PartialEq)]
+/// enum Event {
+///     Created { id: u64, title: String },
+///     Deleted { id: u64 },
+///     Archived,
+/// }
+///
+/// beaver::define! {
+///     EventFactory (Event) {
+///         Created {
+///             id -> |n| n,
+///             title -> |n| format!("event-{}", n),
+///         },
+///         Deleted {
+///             id -> |n| n,
+///         },
+///         Archived {},
+///     }
+/// }
+///
+/// let event_factory = EventFactory::new();
+/// let events = event_factory.build_list(3, |_| {});
+/// assert_eq!(events[1], Event::Deleted { id: 2 });
+/// assert_eq!(events[2], Event::Archived);
+/// let event = event_factory.build_variant("Deleted", |_| {});
+/// assert_eq!(event, Event::Deleted { id: 4 });
+/// ```
+///
+/// If you want a factory which differs from another factory in some fields, you can define a child factory
+/// with `[child factory name] ([struct name]) : [parent factory name] { ... }`.
+/// It inherits fields, transient attributes, traits, hooks and the stub block of the parent,
+/// and fields, traits, hooks and the stub block in its body are applied after the parent's ones.
+/// Generators in its body can read fields which the parent generated, after the transient attributes.
+/// Transient attributes are references there, like the fields defined before in the body.
+/// A child factory has its own sequence by default, which is the same as `sequence own`.
+/// With `sequence shared` at the top of its body, it builds structs with `shared_sequence()` of the parent,
+/// a static sequence which every factory defined with `define!` has.
+/// A parent factory from `new()` has its own counter, so pass the static sequence to
+/// [with_sequence](struct.Factory.html#method.with_sequence) to count parent's structs with it too.
+/// A child factory inherits the template and transient attributes of the parent,
+/// so it can't have `..Default` or a `transient` block.
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Post {
+///     id: u64,
+///     title: String,
+///     approved: bool,
+/// }
+///
+/// beaver::define! {
+///     PostFactory (Post) {
+///         id -> |n| n,
+///         title -> |n| format!("post-{}", n),
+///         approved -> |_| false,
+///     }
+/// }
+///
+/// beaver::define! {
+///     PublishedPostFactory (Post) : PostFactory {
+///         approved -> |_| true,
+///     }
+/// }
+///
+/// beaver::define! {
+///     DraftPostFactory (Post) : PostFactory {
+///         sequence shared,
+///         title -> |n| format!("draft-{}", n),
+///     }
+/// }
+///
+/// let published_post_factory = PublishedPostFactory::new();
+/// let post = published_post_factory.build(|_| {});
+/// assert_eq!(post.title, "post-1");
+/// assert!(post.approved);
+///
+/// let post_factory = PostFactory::new_sync().with_sequence(PostFactory::shared_sequence());
+/// let draft_post_factory = DraftPostFactory::new();
+/// assert_eq!(post_factory.build(|_| {}).id, 1);
+/// assert_eq!(draft_post_factory.build(|_| {}).title, "draft-2");
+/// ```
 #[macro_export]
 macro_rules! define {
     ($($tokens:tt)*) => {
@@ -124,65 +586,884 @@ macro_rules! define {
 #[doc(hidden)]
 macro_rules! beaver_parse {
     (
-        tokens = [pub $factory_name:ident $($rest:tt)*],
-        factory_name = $ignore:tt,
-        $($args:tt)*
+        tokens = [clone $($rest:tt)*],
+        factory_name = $factory_name:tt,
+        vis = $vis:tt,
+        generics = $generics:tt,
+        lifetimes = $lifetimes:tt,
+        types = $types:tt,
+        struct_name = $struct_name:tt,
+        struct_type = [$($struct_type:tt)*],
+        where_clause = $where_clause:tt,
+        template = $ignore:tt,
     ) => {
         $crate::beaver_parse! {
             tokens = [$($rest)*],
             factory_name = $factory_name,
-            public = true,
-            $($args)*
+            vis = $vis,
+            generics = $generics,
+            lifetimes = $lifetimes,
+            types = $types,
+            struct_name = $struct_name,
+            struct_type = [$($struct_type)*],
+            where_clause = $where_clause,
+            template = [$crate::Cloned<$($struct_type)*>],
+        }
+    };
+
+    (
+        tokens = [$(: $parent_factory:ty)? where $($rest:tt)*],
+        factory_name = $factory_name:tt,
+        vis = $vis:tt,
+        generics = $generics:tt,
+        lifetimes = $lifetimes:tt,
+        types = $types:tt,
+        struct_name = $struct_name:tt,
+        struct_type = $struct_type:tt,
+        where_clause = [],
+        template = $template:tt,
+    ) => {
+        $crate::beaver_parse! {
+            where_tokens = [$($rest)*],
+            where_clause = [],
+            parent = [$(: $parent_factory)?],
+            factory_name = $factory_name,
+            vis = $vis,
+            generics = $generics,
+            lifetimes = $lifetimes,
+            types = $types,
+            struct_name = $struct_name,
+            struct_type = $struct_type,
+            template = $template,
+        }
+    };
+
+    (
+        where_tokens = [{$($body:tt)*}],
+        where_clause = $where_clause:tt,
+        parent = [$($parent:tt)*],
+        factory_name = $factory_name:tt,
+        vis = $vis:tt,
+        generics = $generics:tt,
+        lifetimes = $lifetimes:tt,
+        types = $types:tt,
+        struct_name = $struct_name:tt,
+        struct_type = $struct_type:tt,
+        template = $template:tt,
+    ) => {
+        $crate::beaver_parse! {
+            tokens = [$($parent)* {$($body)*}],
+            factory_name = $factory_name,
+            vis = $vis,
+            generics = $generics,
+            lifetimes = $lifetimes,
+            types = $types,
+            struct_name = $struct_name,
+            struct_type = $struct_type,
+            where_clause = $where_clause,
+            template = $template,
+        }
+    };
+
+    (
+        where_tokens = [$token:tt $($rest:tt)*],
+        where_clause = [$($where_clause:tt)*],
+        $($args:tt)*
+    ) => {
+        $crate::beaver_parse! {
+            where_tokens = [$($rest)*],
+            where_clause = [$($where_clause)* $token],
+            $($args)*
+        }
+    };
+
+    (
+        tokens = [pub $factory_name:ident $($rest:tt)*],
+        factory_name = $ignore:tt,
+    ) => {
+        $crate::beaver_parse! {
+            tokens = [$($rest)*],
+            factory_name = $factory_name,
+            vis = [pub],
+            generics = [],
+            lifetimes = [],
+            types = [],
         }
     };
 
     (
         tokens = [$factory_name:ident $($rest:tt)*],
         factory_name = $ignore:tt,
+    ) => {
+        $crate::beaver_parse! {
+            tokens = [$($rest)*],
+            factory_name = $factory_name,
+            vis = [],
+            generics = [],
+            lifetimes = [],
+            types = [],
+        }
+    };
+
+    // Generic parameters are collected as they are, and their names are collected
+    // into `lifetimes` and `types` for the generated factory type.
+    (
+        tokens = [< $($rest:tt)*],
+        factory_name = $factory_name:tt,
+        vis = $vis:tt,
+        generics = [],
+        lifetimes = [],
+        types = [],
+    ) => {
+        $crate::beaver_parse! {
+            generic_tokens = [$($rest)*],
+            depth = [],
+            param = [start],
+            generics = [],
+            lifetimes = [],
+            types = [],
+            factory_name = $factory_name,
+            vis = $vis,
+        }
+    };
+
+    (
+        generic_tokens = [> $($rest:tt)*],
+        depth = [],
+        param = $ignore:tt,
+        generics = $generics:tt,
+        lifetimes = $lifetimes:tt,
+        types = $types:tt,
+        factory_name = $factory_name:tt,
+        vis = $vis:tt,
+    ) => {
+        $crate::beaver_parse! {
+            tokens = [$($rest)*],
+            factory_name = $factory_name,
+            vis = $vis,
+            generics = $generics,
+            lifetimes = $lifetimes,
+            types = $types,
+        }
+    };
+
+    (
+        generic_tokens = [>> $($rest:tt)*],
+        depth = [@],
+        param = $ignore:tt,
+        generics = [$($generics:tt)*],
+        $($args:tt)*
+    ) => {
+        $crate::beaver_parse! {
+            generic_tokens = [> $($rest)*],
+            depth = [],
+            param = [bound],
+            generics = [$($generics)* >],
+            $($args)*
+        }
+    };
+
+    (
+        generic_tokens = [$lifetime:lifetime $($rest:tt)*],
+        depth = [],
+        param = [start],
+        generics = [$($generics:tt)*],
+        lifetimes = [$($lifetimes:tt)*],
+        $($args:tt)*
+    ) => {
+        $crate::beaver_parse! {
+            generic_tokens = [$($rest)*],
+            depth = [],
+            param = [bound],
+            generics = [$($generics)* $lifetime],
+            lifetimes = [$($lifetimes)* $lifetime],
+            $($args)*
+        }
+    };
+
+    (
+        generic_tokens = [$type:ident $($rest:tt)*],
+        depth = [],
+        param = [start],
+        generics = [$($generics:tt)*],
+        lifetimes = $lifetimes:tt,
+        types = [$($types:tt)*],
+        $($args:tt)*
+    ) => {
+        $crate::beaver_parse! {
+            generic_tokens = [$($rest)*],
+            depth = [],
+            param = [bound],
+            generics = [$($generics)* $type],
+            lifetimes = $lifetimes,
+            types = [$($types)* $type],
+            $($args)*
+        }
+    };
+
+    (
+        generic_tokens = [, $($rest:tt)*],
+        depth = [],
+        param = $ignore:tt,
+        generics = [$($generics:tt)*],
+        $($args:tt)*
+    ) => {
+        $crate::beaver_parse! {
+            generic_tokens = [$($rest)*],
+            depth = [],
+            param = [start],
+            generics = [$($generics)* ,],
+            $($args)*
+        }
+    };
+
+    (
+        generic_tokens = [< $($rest:tt)*],
+        depth = [$($depth:tt)*],
+        param = $param:tt,
+        generics = [$($generics:tt)*],
+        $($args:tt)*
+    ) => {
+        $crate::beaver_parse! {
+            generic_tokens = [$($rest)*],
+            depth = [@ $($depth)*],
+            param = $param,
+            generics = [$($generics)* <],
+            $($args)*
+        }
+    };
+
+    (
+        generic_tokens = [> $($rest:tt)*],
+        depth = [@ $($depth:tt)*],
+        param = $param:tt,
+        generics = [$($generics:tt)*],
+        $($args:tt)*
+    ) => {
+        $crate::beaver_parse! {
+            generic_tokens = [$($rest)*],
+            depth = [$($depth)*],
+            param = $param,
+            generics = [$($generics)* >],
+            $($args)*
+        }
+    };
+
+    (
+        generic_tokens = [>> $($rest:tt)*],
+        depth = [@ @ $($depth:tt)*],
+        param = $param:tt,
+        generics = [$($generics:tt)*],
+        $($args:tt)*
+    ) => {
+        $crate::beaver_parse! {
+            generic_tokens = [$($rest)*],
+            depth = [$($depth)*],
+            param = $param,
+            generics = [$($generics)* >>],
+            $($args)*
+        }
+    };
+
+    (
+        generic_tokens = [$token:tt $($rest:tt)*],
+        depth = $depth:tt,
+        param = $param:tt,
+        generics = [$($generics:tt)*],
+        $($args:tt)*
+    ) => {
+        $crate::beaver_parse! {
+            generic_tokens = [$($rest)*],
+            depth = $depth,
+            param = $param,
+            generics = [$($generics)* $token],
+            $($args)*
+        }
+    };
+
+    (
+        tokens = [($($struct_type:tt)*) $($rest:tt)*],
+        factory_name = $factory_name:tt,
+        vis = $vis:tt,
+        generics = $generics:tt,
+        lifetimes = $lifetimes:tt,
+        types = $types:tt,
+    ) => {
+        $crate::beaver_parse! {
+            path_tokens = [$($struct_type)*],
+            path = [],
+            tokens = [$($rest)*],
+            factory_name = $factory_name,
+            vis = $vis,
+            generics = $generics,
+            lifetimes = $lifetimes,
+            types = $types,
+            struct_type = [$($struct_type)*],
+        }
+    };
+
+    // The path of a struct without generic arguments is used in struct literals.
+    (
+        path_tokens = [$(< $($args:tt)*)?],
+        path = $path:tt,
+        tokens = $tokens:tt,
+        factory_name = $factory_name:tt,
+        vis = $vis:tt,
+        generics = $generics:tt,
+        lifetimes = $lifetimes:tt,
+        types = $types:tt,
+        struct_type = $struct_type:tt,
+    ) => {
+        $crate::beaver_parse! {
+            tokens = $tokens,
+            factory_name = $factory_name,
+            vis = $vis,
+            generics = $generics,
+            lifetimes = $lifetimes,
+            types = $types,
+            struct_name = $path,
+            struct_type = $struct_type,
+            where_clause = [],
+            template = [::std::string::String],
+        }
+    };
+
+    (
+        path_tokens = [$token:tt $($rest:tt)*],
+        path = [$($path:tt)*],
+        $($args:tt)*
+    ) => {
+        $crate::beaver_parse! {
+            path_tokens = [$($rest)*],
+            path = [$($path)* $token],
+            $($args)*
+        }
+    };
+
+    // A body which only has `Variant { ... }` items defines a factory of an enum.
+    (
+        tokens = [{
+            $variant:ident {$($variant_body:tt)*}
+            $(, $pending:ident {$($pending_body:tt)*})* $(,)?
+        }],
+        $($args:tt)*
+    ) => {
+        $crate::beaver_parse! {
+            body = [$($variant_body)*],
+            fields = [],
+            names = [],
+            variant = $variant,
+            variants = [],
+            pending = [$($pending {$($pending_body)*})*],
+            $($args)*
+        }
+    };
+
+    // `Factory (Newtype) -> generator` is a shorthand of `Factory (Newtype) { 0 -> generator }`.
+    (
+        tokens = [-> $($generator:tt)*],
+        $($args:tt)*
+    ) => {
+        $crate::beaver_parse! {
+            tokens = [{0 -> $($generator)*}],
+            $($args)*
+        }
+    };
+
+    (
+        tokens = [{$($body:tt)*}],
+        $($args:tt)*
+    ) => {
+        $crate::beaver_parse! {
+            body = [$($body)*],
+            fields = [],
+            names = [],
+            traits = [],
+            transient = [()],
+            hooks = [],
+            stub = [],
+            parent_factory = [],
+            $($args)*
+        }
+    };
+
+    (
+        tokens = [: $parent_factory:ty {$($body:tt)*}],
+        $($args:tt)*
+    ) => {
+        $crate::beaver_parse! {
+            body = [$($body)*],
+            fields = [],
+            names = [],
+            traits = [],
+            transient = [()],
+            hooks = [],
+            stub = [],
+            parent_factory = [$parent_factory, own],
+            $($args)*
+        }
+    };
+    (
+        body = [trait $trait_name:ident {$($trait_body:tt)*} $($rest:tt)*],
+        fields = [$($fields:tt)*],
+        names = [$($names:tt)*],
+        traits = [$($traits:tt)*],
+        $($args:tt)*
+    ) => {
+        $crate::beaver_parse! {
+            body = [$($trait_body)*],
+            fields = [],
+            names = [],
+            traits = [],
+            trait_name = $trait_name,
+            parent = [
+                body = [$($rest)*],
+                fields = [$($fields)*],
+                names = [$($names)*],
+                traits = [$($traits)*],
+                $($args)*
+            ],
+        }
+    };
+
+    (
+        body = [],
+        fields = [$($trait_fields:tt)*],
+        names = [$($ignore:tt)*],
+        traits = [],
+        trait_name = $trait_name:ident,
+        parent = [
+            body = [$($rest:tt)*],
+            fields = [$($fields:tt)*],
+            names = [$($names:tt)*],
+            traits = [$($traits:tt)*],
+            $($args:tt)*
+        ],
+    ) => {
+        $crate::beaver_parse! {
+            body = [$($rest)*],
+            fields = [$($fields)*],
+            names = [$($names)*],
+            traits = [$($traits)* $trait_name = [$($trait_fields)*];],
+            $($args)*
+        }
+    };
+
+    (
+        body = [transient $transient_name:ident {$($tfname:ident: $tftype:ty = $tfvalue:expr),*$(,)?} $($rest:tt)*],
+        fields = [$($fields:tt)*],
+        names = [$($names:tt)*],
+        traits = [$($traits:tt)*],
+        transient = [$ignore:tt],
+        $($args:tt)*
+    ) => {
+        $crate::beaver_parse! {
+            body = [$($rest)*],
+            fields = [$($fields)*],
+            names = [$($names)*],
+            traits = [$($traits)*],
+            transient = [$transient_name {$($tfname: $tftype = $tfvalue,)*}],
+            $($args)*
+        }
+    };
+
+    (
+        body = [transient {$($transient_body:tt)*} $($rest:tt)*],
+        $($args:tt)*
+    ) => {
+        compile_error!("Transient attributes need a struct name and types, such as `transient PostTransient { tags_count: u64 = 3 }`.");
+    };
+
+    // A child factory keeps the stub ids of its parent unless its stub block has them.
+    (
+        body = [stub {$($stub_body:tt)*} $($rest:tt)*],
+        fields = $fields:tt,
+        names = $names:tt,
+        traits = $traits:tt,
+        transient = $transient:tt,
+        hooks = $hooks:tt,
+        stub = $stub:tt,
+        parent_factory = [$parent:ty, $sequence:ident],
+        $($args:tt)*
+    ) => {
+        $crate::beaver_parse! {
+            body = [stub (::std::option::Option::None) {$($stub_body)*} $($rest)*],
+            fields = $fields,
+            names = $names,
+            traits = $traits,
+            transient = $transient,
+            hooks = $hooks,
+            stub = $stub,
+            parent_factory = [$parent, $sequence],
+            $($args)*
+        }
+    };
+
+    (
+        body = [stub {$($stub_body:tt)*} $($rest:tt)*],
+        $($args:tt)*
+    ) => {
+        $crate::beaver_parse! {
+            body = [stub ($crate::STUB_ID_START) {$($stub_body)*} $($rest)*],
+            $($args)*
+        }
+    };
+
+    (
+        body = [stub ($from:expr) {$($stub_body:tt)*} $($rest:tt)*],
+        fields = [$($fields:tt)*],
+        names = [$($names:tt)*],
+        traits = [$($traits:tt)*],
+        transient = [$($transient:tt)*],
+        hooks = [$($hooks:tt)*],
+        stub = $ignore:tt,
+        $($args:tt)*
+    ) => {
+        $crate::beaver_parse! {
+            body = [$($stub_body)*],
+            fields = [],
+            names = [],
+            traits = [],
+            stub_from = [$from],
+            parent = [
+                body = [$($rest)*],
+                fields = [$($fields)*],
+                names = [$($names)*],
+                traits = [$($traits)*],
+                transient = [$($transient)*],
+                hooks = [$($hooks)*],
+                $($args)*
+            ],
+        }
+    };
+
+    (
+        body = [],
+        fields = [$($stub_fields:tt)*],
+        names = [$($ignore:tt)*],
+        traits = [],
+        stub_from = $from:tt,
+        parent = [
+            body = [$($rest:tt)*],
+            fields = [$($fields:tt)*],
+            names = [$($names:tt)*],
+            traits = [$($traits:tt)*],
+            transient = [$($transient:tt)*],
+            hooks = [$($hooks:tt)*],
+            $($args:tt)*
+        ],
+    ) => {
+        $crate::beaver_parse! {
+            body = [$($rest)*],
+            fields = [$($fields)*],
+            names = [$($names)*],
+            traits = [$($traits)*],
+            transient = [$($transient)*],
+            hooks = [$($hooks)*],
+            stub = [$from [$($stub_fields)*]],
+            $($args)*
+        }
+    };
+
+    (
+        body = [$kind:ident |$m:pat_param, $n:pat_param| $hook:block $($rest:tt)*],
+        fields = [$($fields:tt)*],
+        names = [$($names:tt)*],
+        traits = [$($traits:tt)*],
+        transient = [$($transient:tt)*],
+        hooks = [$($hooks:tt)*],
+        $($args:tt)*
+    ) => {
+        $crate::beaver_parse! {
+            body = [$($rest)*],
+            fields = [$($fields)*],
+            names = [$($names)*],
+            traits = [$($traits)*],
+            transient = [$($transient)*],
+            hooks = [$($hooks)* $kind [$m, $n => $hook]],
+            $($args)*
+        }
+    };
+
+    // `sequence own` or `sequence shared` chooses the sequence of a child factory.
+    (
+        body = [sequence own $(, $($rest:tt)*)?],
+        fields = $fields:tt,
+        names = $names:tt,
+        traits = $traits:tt,
+        transient = $transient:tt,
+        hooks = $hooks:tt,
+        stub = $stub:tt,
+        parent_factory = [$parent:ty, $ignore:ident],
+        $($args:tt)*
+    ) => {
+        $crate::beaver_parse! {
+            body = [$($($rest)*)?],
+            fields = $fields,
+            names = $names,
+            traits = $traits,
+            transient = $transient,
+            hooks = $hooks,
+            stub = $stub,
+            parent_factory = [$parent, own],
+            $($args)*
+        }
+    };
+
+    (
+        body = [sequence shared $(, $($rest:tt)*)?],
+        fields = $fields:tt,
+        names = $names:tt,
+        traits = $traits:tt,
+        transient = $transient:tt,
+        hooks = $hooks:tt,
+        stub = $stub:tt,
+        parent_factory = [$parent:ty, $ignore:ident],
+        $($args:tt)*
+    ) => {
+        $crate::beaver_parse! {
+            body = [$($($rest)*)?],
+            fields = $fields,
+            names = $names,
+            traits = $traits,
+            transient = $transient,
+            hooks = $hooks,
+            stub = $stub,
+            parent_factory = [$parent, shared],
+            $($args)*
+        }
+    };
+
+    (
+        body = [sequence $sequence:ident $($rest:tt)*],
+        fields = $fields:tt,
+        names = $names:tt,
+        traits = $traits:tt,
+        transient = $transient:tt,
+        hooks = $hooks:tt,
+        stub = $stub:tt,
+        parent_factory = [$parent:ty, $ignore:ident],
+        $($args:tt)*
+    ) => {
+        compile_error!(concat!("Unexpected sequence `", stringify!($sequence), "`. Please use `own` or `shared`."));
+    };
+
+    (
+        body = [sequence $sequence:ident $($rest:tt)*],
+        fields = $fields:tt,
+        names = $names:tt,
+        traits = $traits:tt,
+        transient = $transient:tt,
+        hooks = $hooks:tt,
+        stub = $stub:tt,
+        parent_factory = [],
+        $($args:tt)*
+    ) => {
+        compile_error!("`sequence` is only available in a child factory, such as `Child (Struct) : Parent { ... }`.");
+    };
+
+    // `..Default` or `..default_model(expr)` at the end of a body fills unlisted fields.
+    (
+        body = [.. Default $(,)?],
+        $($args:tt)*
+    ) => {
+        $crate::beaver_parse! {
+            body = [.. default_model(::std::default::Default::default())],
+            $($args)*
+        }
+    };
+
+    (
+        body = [.. default_model($base:expr) $(,)?],
+        fields = $fields:tt,
+        names = $names:tt,
+        traits = $traits:tt,
+        transient = $transient:tt,
+        hooks = $hooks:tt,
+        stub = $stub:tt,
+        parent_factory = [],
+        $($args:tt)*
+    ) => {
+        $crate::beaver_parse! {
+            body = [],
+            fields = $fields,
+            names = $names,
+            traits = $traits,
+            transient = $transient,
+            hooks = $hooks,
+            stub = $stub,
+            parent_factory = [],
+            base = [$base],
+            $($args)*
+        }
+    };
+
+    (
+        body = [.. default_model($base:expr) $(,)?],
+        fields = $fields:tt,
+        names = $names:tt,
+        traits = $traits:tt,
+        transient = $transient:tt,
+        hooks = $hooks:tt,
+        stub = $stub:tt,
+        parent_factory = [$parent:ty, $sequence:ident],
+        $($args:tt)*
+    ) => {
+        compile_error!("A child factory builds structs from its parent's template, so it can't have `..Default` or `..default_model(...)`.");
+    };
+
+    (
+        body = [, $($rest:tt)*],
+        $($args:tt)*
+    ) => {
+        $crate::beaver_parse! {
+            body = [$($rest)*],
+            $($args)*
+        }
+    };
+
+    (
+        body = [$fname:ident -> |$n:pat_param, $t:pat_param| $fvalue:expr $(, $($rest:tt)*)?],
+        fields = [$($fields:tt)*],
+        names = [$($names:tt)*],
+        $($args:tt)*
+    ) => {
+        $crate::beaver_parse! {
+            body = [$($($rest)*)?],
+            fields = [$($fields)* $fname = [$($names)*] [$fname: $n, $t => $fvalue];],
+            names = [$($names)* $fname],
+            $($args)*
+        }
+    };
+
+    (
+        body = [$fname:ident -> $fvalue:expr $(, $($rest:tt)*)?],
+        fields = [$($fields:tt)*],
+        names = [$($names:tt)*],
+        $($args:tt)*
+    ) => {
+        $crate::beaver_parse! {
+            body = [$($($rest)*)?],
+            fields = [$($fields)* $fname = [$($names)*] [$fvalue];],
+            names = [$($names)* $fname],
+            $($args)*
+        }
+    };
+
+    // A nested path such as `file.path` overrides a field of the value generated for `file`,
+    // so it isn't in `names` either.
+    (
+        body = [$fname:ident $(. $fpath:tt)+ -> |$n:pat_param, $t:pat_param| $fvalue:expr $(, $($rest:tt)*)?],
+        fields = [$($fields:tt)*],
+        names = [$($names:tt)*],
+        $($args:tt)*
+    ) => {
+        $crate::beaver_parse! {
+            body = [$($($rest)*)?],
+            fields = [$($fields)* $fname $(.$fpath)+ = [$($names)*] [$fname $(.$fpath)+: $n, $t => $fvalue];],
+            names = [$($names)*],
+            $($args)*
+        }
+    };
+
+    (
+        body = [$fname:ident $(. $fpath:tt)+ -> $fvalue:expr $(, $($rest:tt)*)?],
+        fields = [$($fields:tt)*],
+        names = [$($names:tt)*],
+        $($args:tt)*
+    ) => {
+        $crate::beaver_parse! {
+            body = [$($($rest)*)?],
+            fields = [$($fields)* $fname $(.$fpath)+ = [$($names)*] [$fvalue];],
+            names = [$($names)*],
+            $($args)*
+        }
+    };
+
+    // Positional fields of a tuple struct can't be referred from a context, so they aren't in `names`.
+    (
+        body = [$index:tt -> |$n:pat_param, $t:pat_param| $fvalue:expr $(, $($rest:tt)*)?],
+        fields = [$($fields:tt)*],
+        names = [$($names:tt)*],
         $($args:tt)*
     ) => {
         $crate::beaver_parse! {
-            tokens = [$($rest)*],
-            factory_name = $factory_name,
-            public = false,
+            body = [$($($rest)*)?],
+            fields = [$($fields)* $index = [$($names)*] [$index: $n, $t => $fvalue];],
+            names = [$($names)*],
             $($args)*
         }
     };
 
     (
-        tokens = [($struct_name:ident) $($rest:tt)*],
-        factory_name = $factory_name:tt,
-        public = $public:ident,
+        body = [$index:tt -> $fvalue:expr $(, $($rest:tt)*)?],
+        fields = [$($fields:tt)*],
+        names = [$($names:tt)*],
         $($args:tt)*
     ) => {
         $crate::beaver_parse! {
-            tokens = [$($rest)*],
-            factory_name = $factory_name,
-            public = $public,
-            struct_name = $struct_name,
-            fields = [],
+            body = [$($($rest)*)?],
+            fields = [$($fields)* $index = [$($names)*] [$fvalue];],
+            names = [$($names)*],
+            $($args)*
         }
     };
 
     (
-        tokens = [{$($fname:ident -> $fvalue:expr),*$(,)?}],
-        factory_name = $factory_name:tt,
-        public = $public:ident,
-        struct_name = $struct_name:tt,
-        fields = [$($ignore:tt)*],
+        body = [$fname:ident $(, $($rest:tt)*)?],
+        fields = [$($fields:tt)*],
+        names = [$($names:tt)*],
+        traits = [],
+        stub_from = $from:tt,
         $($args:tt)*
     ) => {
         $crate::beaver_parse! {
-            tokens = [],
-            factory_name = $factory_name,
-            public = $public,
-            struct_name = $struct_name,
-            fields = [$($fname = ($fvalue);)*],
+            body = [$($($rest)*)?],
+            fields = [$($fields)* $fname = [$($names)*] [$crate::Stub::stub];],
+            names = [$($names)* $fname],
+            traits = [],
+            stub_from = $from,
+            $($args)*
+        }
+    };
+
+    (
+        body = [],
+        fields = [$($fields:tt)*],
+        names = $ignore:tt,
+        variant = $variant:ident,
+        variants = [$($variants:tt)*],
+        pending = [$next:ident {$($next_body:tt)*} $($pending:tt)*],
+        $($args:tt)*
+    ) => {
+        $crate::beaver_parse! {
+            body = [$($next_body)*],
+            fields = [],
+            names = [],
+            variant = $next,
+            variants = [$($variants)* $variant [$($fields)*]],
+            pending = [$($pending)*],
+            $($args)*
+        }
+    };
+
+    (
+        body = [],
+        fields = [$($fields:tt)*],
+        names = $ignore:tt,
+        variant = $variant:ident,
+        variants = [$($variants:tt)*],
+        pending = [],
+        $($args:tt)*
+    ) => {
+        $crate::beaver_enum_impl! {
+            variants = [$($variants)* $variant [$($fields)*]],
+            $($args)*
         }
     };
 
     (
-        tokens = [],
+        body = [],
         $($args:tt)*
     ) => {
         $crate::beaver_factory_impl! {$($args)*}
@@ -193,72 +1474,763 @@ macro_rules! beaver_parse {
 #[doc(hidden)]
 macro_rules! beaver_factory_impl {
     (
+        fields = [$($fname:tt $(. $fpath:tt)* = $fnames:tt [$($fvalue:tt)*];)*],
+        names = $names:tt,
+        traits = [$($trait_name:ident = [$($tfname:tt $(. $tfpath:tt)* = $tfnames:tt [$($tfvalue:tt)*];)*];)*],
+        transient = [()],
+        hooks = [$($kind:ident [$m:pat_param, $hn:pat_param => $hook:block])*],
+        stub = [$([$stub_from:expr] [$($sfname:tt $(. $sfpath:tt)* = $sfnames:tt [$($sfvalue:tt)*];)*])?],
+        parent_factory = [$parent:ty, $sequence:ident],
+        factory_name = $factory_name:ident,
+        vis = [$($vis:tt)*],
+        generics = [$($generics:tt)*],
+        lifetimes = [$($lifetime:lifetime)*],
+        types = [$($type:ident)*],
+        struct_name = $struct_name:tt,
+        struct_type = [$struct:ty],
+        where_clause = [$($where_clause:tt)*],
+        template = $ignore:tt,
+    ) => {
+        $crate::beaver_factory_struct! {
+            vis = [$($vis)*],
+            factory_name = $factory_name,
+            generics = [$($generics)*],
+            lifetimes = [$($lifetime)*],
+            types = [$($type)*],
+            where_clause = [$($where_clause)*],
+        }
+
+        #[allow(clippy::redundant_closure)]
+        impl<$($generics)*> $crate::Definition for $factory_name<$($lifetime,)* $($type),*>
+        where
+            $($where_clause)*
+        {
+            type Struct = $struct;
+            type Transient = <$parent as $crate::Definition>::Transient;
+            type Template = <$parent as $crate::Definition>::Template;
+
+            fn factory<'__beaver, S: $crate::Counter>() -> $crate::Factory<'__beaver, $struct, Self::Transient, S, Self::Template> {
+                const __BEAVER_FACTORY: &str = stringify!($factory_name);
+                let factory = <$parent as $crate::Definition>::factory::<S>()
+                    .with_fields(<S as $crate::Counter>::gen_func(|m: &mut $struct, n, t: &Self::Transient| {
+                        $(m.$fname $(.$fpath)* = $crate::beaver_field_value!([$($fvalue)*], n, t, $fnames, @scope m);)*
+                    }))
+                    $(.with_trait(
+                        stringify!($trait_name),
+                        <S as $crate::Counter>::gen_func(|m: &mut $struct, n, t: &Self::Transient| {
+                            $(m.$tfname $(.$tfpath)* = $crate::beaver_field_value!([$($tfvalue)*], n, t, $names, @scope m);)*
+                        })
+                    ))*
+                    $(.with_stub_fields(
+                        ::std::option::Option::<u64>::from($stub_from),
+                        <S as $crate::Counter>::gen_func(|m: &mut $struct, n, t: &Self::Transient| {
+                            $(m.$sfname $(.$sfpath)* = $crate::beaver_field_value!([$($sfvalue)*], n, t, $names, @scope m);)*
+                        })
+                    ))?;
+                $(let factory = $crate::beaver_hook!($kind, factory, <S as $crate::Counter>::hook(|$m: &mut $struct, $hn: u64| $hook));)*
+                factory
+            }
+        }
+
+        impl<$($generics)*> $factory_name<$($lifetime,)* $($type),*>
+        where
+            $($where_clause)*
+        {
+            $crate::beaver_child_constructors! {
+                sequence = $sequence,
+                parent = $parent,
+                vis = [$($vis)*],
+                struct_type = [$struct],
+            }
+
+            $($vis)* fn build(n: u64) -> $struct
+            {
+                Self::new().build_n(n, |_| {})
+            }
+
+            $($vis)* fn build_list(number: u64, n: u64) -> Vec<$struct>
+            {
+                Self::new().build_list_n(number, n, |_| {})
+            }
+        }
+    };
+
+    (
+        fields = [$($fields:tt)*],
+        names = [$($names:tt)*],
+        traits = [$($traits:tt)*],
+        transient = [$transient_name:ident {$($tfname:ident: $tftype:ty = $tfvalue:expr,)*}],
+        hooks = [$($hooks:tt)*],
+        stub = $stub:tt,
+        parent_factory = [],
+        $(base = $base:tt,)?
+        factory_name = $factory_name:ident,
+        vis = [$($vis:tt)*],
+        $($args:tt)*
+    ) => {
+        $($vis)* struct $transient_name {
+            $(pub $tfname: $tftype,)*
+        }
+
+        impl Default for $transient_name {
+            fn default() -> Self {
+                $transient_name {
+                    $($tfname: $tfvalue,)*
+                }
+            }
+        }
+
+        const _: () = {
+            // The scope of a child generator, which reads transient attributes before fields of the struct.
+            pub struct Scope<'t, T> {
+                $(pub $tfname: &'t $tftype,)*
+                __beaver_model: &'t T,
+            }
+
+            impl<T> ::std::ops::Deref for Scope<'_, T> {
+                type Target = T;
+
+                fn deref(&self) -> &T {
+                    self.__beaver_model
+                }
+            }
+
+            impl<T> $crate::Scope<T> for $transient_name {
+                type Scope<'t> = Scope<'t, T> where T: 't;
+
+                fn scope<'t>(transient: &'t Self, model: &'t T) -> Scope<'t, T> {
+                    Scope {
+                        $($tfname: &transient.$tfname,)*
+                        __beaver_model: model,
+                    }
+                }
+            }
+        };
+
+        $crate::beaver_factory_impl! {
+            fields = [$($fields)*],
+            names = [$($names)*],
+            traits = [$($traits)*],
+            transient = [$transient_name],
+            hooks = [$($hooks)*],
+            stub = $stub,
+            parent_factory = [],
+            $(base = $base,)?
+            factory_name = $factory_name,
+            vis = [$($vis)*],
+            $($args)*
+        }
+    };
+
+    (
+        fields = [$($fname:tt $(. $fpath:tt)* = $fnames:tt [$($fvalue:tt)*];)*],
+        names = $names:tt,
+        traits = [$($trait_name:ident = [$($tfname:tt $(. $tfpath:tt)* = $tfnames:tt [$($tfvalue:tt)*];)*];)*],
+        transient = [$transient:ty],
+        hooks = [$($kind:ident [$m:pat_param, $hn:pat_param => $hook:block])*],
+        stub = [$([$stub_from:expr] [$($sfname:tt $(. $sfpath:tt)* = $sfnames:tt [$($sfvalue:tt)*];)*])?],
+        parent_factory = [],
+        $(base = [$($base:tt)*],)?
         factory_name = $factory_name:ident,
-        public = false,
-        struct_name = $struct:ident,
-        fields = [$($fname:ident = $fvalue:expr;)*],
+        vis = [$($vis:tt)*],
+        generics = [$($generics:tt)*],
+        lifetimes = [$($lifetime:lifetime)*],
+        types = [$($type:ident)*],
+        struct_name = $struct_name:tt,
+        struct_type = [$struct:ty],
+        where_clause = [$($where_clause:tt)*],
+        template = [$template:ty],
     ) => {
-        pub struct $factory_name;
+        $crate::beaver_factory_struct! {
+            vis = [$($vis)*],
+            factory_name = $factory_name,
+            generics = [$($generics)*],
+            lifetimes = [$($lifetime)*],
+            types = [$($type)*],
+            where_clause = [$($where_clause)*],
+        }
+
+        impl<$($generics)*> $crate::Definition for $factory_name<$($lifetime,)* $($type),*>
+        where
+            $($where_clause)*
+        {
+            type Struct = $struct;
+            type Transient = $transient;
+            type Template = $template;
+
+            fn factory<'__beaver, S: $crate::Counter>() -> $crate::Factory<'__beaver, $struct, $transient, S, $template> {
+                Self::new_with_counter()
+            }
+        }
+
+        #[allow(clippy::redundant_closure)]
+        impl<$($generics)*> $factory_name<$($lifetime,)* $($type),*>
+        where
+            $($where_clause)*
+        {
+            $($vis)* fn new<'__beaver>() -> $crate::Factory<'__beaver, $struct, $transient, ::std::cell::Cell<u64>, $template>
+            {
+                Self::new_with_counter()
+            }
+
+            $($vis)* fn new_sync<'__beaver>() -> $crate::SyncFactory<'__beaver, $struct, $transient, $template>
+            {
+                Self::new_with_counter()
+            }
 
-        impl $factory_name {
-            fn new<'a>() -> $crate::Factory<'a, $struct>
+            $crate::beaver_shared_sequence!([$($vis)*]);
+
+            #[doc(hidden)]
+            fn new_with_counter<'__beaver, S: $crate::Counter>() -> $crate::Factory<'__beaver, $struct, $transient, S, $template>
             {
-                $crate::new(
-                    $struct {$($fname: $fvalue(1),)*},
-                    Box::new(|m: &mut $struct, n| {$(m.$fname = $fvalue(n));*})
+                const __BEAVER_FACTORY: &str = stringify!($factory_name);
+                // Every field is generated on every build, so the template doesn't consume named sequences.
+                let model = $crate::peek(|| {
+                    let t = &<$transient>::default();
+                    $crate::beaver_template! {
+                        fields = [$($fname $(.$fpath)* = $fnames [$($fvalue)*];)*],
+                        values = [],
+                        struct_name = $struct_name,
+                        base = [$($($base)*)?],
+                        transient = t,
+                        seq = 1,
+                    }
+                });
+                let factory = $crate::new_with_counter(
+                    model,
+                    <S as $crate::Counter>::gen_func(|m: &mut $struct, n, t: &$transient| {
+                        $(m.$fname $(.$fpath)* = $crate::beaver_field_value!([$($fvalue)*], n, t, $fnames, m);)*
+                    })
                 )
+                $(.with_trait(
+                    stringify!($trait_name),
+                    <S as $crate::Counter>::gen_func(|m: &mut $struct, n, t: &$transient| {
+                        $(m.$tfname $(.$tfpath)* = $crate::beaver_field_value!([$($tfvalue)*], n, t, $names, m);)*
+                    })
+                ))*
+                $(.with_stub(
+                    $stub_from,
+                    <S as $crate::Counter>::gen_func(|m: &mut $struct, n, t: &$transient| {
+                        $(m.$sfname $(.$sfpath)* = $crate::beaver_field_value!([$($sfvalue)*], n, t, $names, m);)*
+                    })
+                ))?;
+                $(let factory = $crate::beaver_hook!($kind, factory, <S as $crate::Counter>::hook(|$m: &mut $struct, $hn: u64| $hook));)*
+                factory
             }
 
-            fn build<'a>(n: u16) -> $struct
+            $($vis)* fn build(n: u64) -> $struct
             {
-                $crate::new(
-                    $struct {$($fname: $fvalue(1),)*},
-                    Box::new(|m: &mut $struct, n| {$(m.$fname = $fvalue(n));*})
-                ).build_n(n, |_| {})
+                Self::new().build_n(n, |_| {})
             }
 
-            fn build_list<'a>(number: u16, n: u16) -> Vec<$struct>
+            $($vis)* fn build_list(number: u64, n: u64) -> Vec<$struct>
             {
-                $crate::new(
-                    $struct {$($fname: $fvalue(1),)*},
-                    Box::new(|m: &mut $struct, n| {$(m.$fname = $fvalue(n));*})
-                ).build_list_n(number, n, |_| {})
+                Self::new().build_list_n(number, n, |_| {})
             }
         }
     };
 
     (
+        fields = $fields:tt,
+        names = $names:tt,
+        traits = $traits:tt,
+        transient = [$transient_name:ident $transient_body:tt],
+        hooks = $hooks:tt,
+        stub = $stub:tt,
+        parent_factory = [$parent:ty, $sequence:ident],
+        $($args:tt)*
+    ) => {
+        compile_error!("A child factory inherits transient attributes of its parent, so it can't define them.");
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! beaver_enum_impl {
+    (
+        variants = [$($variant:ident [$($fname:tt = $fnames:tt [$($fvalue:tt)*];)*])*],
         factory_name = $factory_name:ident,
-        public = true,
-        struct_name = $struct:ident,
-        fields = [$($fname:ident = $fvalue:expr;)*],
+        vis = [$($vis:tt)*],
+        generics = [$($generics:tt)*],
+        lifetimes = [$($lifetime:lifetime)*],
+        types = [$($type:ident)*],
+        struct_name = $enum_name:tt,
+        struct_type = [$enum:ty],
+        where_clause = [$($where_clause:tt)*],
+        template = [$template:ty],
     ) => {
-        pub struct $factory_name;
+        $crate::beaver_factory_struct! {
+            vis = [$($vis)*],
+            factory_name = $factory_name,
+            generics = [$($generics)*],
+            lifetimes = [$($lifetime)*],
+            types = [$($type)*],
+            where_clause = [$($where_clause)*],
+        }
+
+        impl<$($generics)*> $crate::Definition for $factory_name<$($lifetime,)* $($type),*>
+        where
+            $($where_clause)*
+        {
+            type Struct = $enum;
+            type Transient = ();
+            type Template = $template;
 
-        impl $factory_name {
-            pub fn new<'a>() -> $crate::Factory<'a, $struct>
+            fn factory<'__beaver, S: $crate::Counter>() -> $crate::Factory<'__beaver, $enum, (), S, $template> {
+                Self::new_with_counter()
+            }
+        }
+
+        impl<$($generics)*> $factory_name<$($lifetime,)* $($type),*>
+        where
+            $($where_clause)*
+        {
+            $($vis)* fn new<'__beaver>() -> $crate::Factory<'__beaver, $enum, (), ::std::cell::Cell<u64>, $template>
             {
-                $crate::new(
-                    $struct {$($fname: $fvalue(1),)*},
-                    Box::new(|m: &mut $struct, n| {$(m.$fname = $fvalue(n));*})
-                )
+                Self::new_with_counter()
+            }
+
+            $($vis)* fn new_sync<'__beaver>() -> $crate::SyncFactory<'__beaver, $enum, (), $template>
+            {
+                Self::new_with_counter()
+            }
+
+            /// Returns the names and the generators of the variants in the defined order.
+            #[doc(hidden)]
+            fn variants() -> ::std::vec::Vec<(&'static str, fn(u64, &()) -> $enum)> {
+                const __BEAVER_FACTORY: &str = stringify!($factory_name);
+                vec![$(
+                    (stringify!($variant), |n, t| {
+                        $crate::beaver_template! {
+                            fields = [$($fname = $fnames [$($fvalue)*];)*],
+                            values = [],
+                            struct_name = [$enum_name::$variant],
+                            base = [],
+                            transient = t,
+                            seq = n,
+                        }
+                    }),
+                )*]
+            }
+
+            #[doc(hidden)]
+            fn new_with_counter<'__beaver, S: $crate::Counter>() -> $crate::Factory<'__beaver, $enum, (), S, $template>
+            {
+                let variants = Self::variants();
+                let model = $crate::peek(|| (variants[0].1)(1, &()));
+                // Variants are built in turn by default, from the list the factory keeps.
+                let cycled = variants.clone();
+                let mut factory = $crate::new_with_counter(
+                    model,
+                    <S as $crate::Counter>::gen_func(move |m: &mut $enum, n, t: &()| {
+                        *m = ($crate::cycle(&cycled, n).1)(n, t);
+                    })
+                );
+                for (name, generate) in variants {
+                    factory = factory.with_variant(
+                        name,
+                        <S as $crate::Counter>::gen_func(move |m: &mut $enum, n, t: &()| *m = generate(n, t)),
+                    );
+                }
+                factory
             }
 
-            pub fn build<'a>(n: u16) -> $struct
+            $($vis)* fn build(n: u64) -> $enum
             {
-                $crate::new(
-                    $struct {$($fname: $fvalue(1),)*},
-                    Box::new(|m: &mut $struct, n| {$(m.$fname = $fvalue(n));*})
-                ).build_n(n, |_| {})
+                Self::new().build_n(n, |_| {})
             }
 
-            pub fn build_list<'a>(number: u16, n: u16) -> Vec<$struct>
+            $($vis)* fn build_list(number: u64, n: u64) -> Vec<$enum>
             {
-                $crate::new(
-                    $struct {$($fname: $fvalue(1),)*},
-                    Box::new(|m: &mut $struct, n| {$(m.$fname = $fvalue(n));*})
-                ).build_list_n(number, n, |_| {})
+                Self::new().build_list_n(number, n, |_| {})
+            }
+        }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! beaver_shared_sequence {
+    ([$($vis:tt)*]) => {
+        /// Returns the static sequence which the factory shares across the process.
+        ///
+        /// Factories built with `new` or `new_sync` don't count with it. Child factories with
+        /// `sequence shared` do, and so does a factory passed it by `with_sequence`.
+        $($vis)* fn shared_sequence() -> ::std::sync::Arc<::std::sync::atomic::AtomicU64> {
+            static SEQUENCE: ::std::sync::OnceLock<::std::sync::Arc<::std::sync::atomic::AtomicU64>> =
+                ::std::sync::OnceLock::new();
+            $crate::shared_counter(&SEQUENCE)
+        }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! beaver_child_constructors {
+    (
+        sequence = own,
+        parent = $parent:ty,
+        vis = [$($vis:tt)*],
+        struct_type = [$struct:ty],
+    ) => {
+        $($vis)* fn new<'__beaver>() -> $crate::Factory<
+            '__beaver,
+            $struct,
+            <Self as $crate::Definition>::Transient,
+            ::std::cell::Cell<u64>,
+            <Self as $crate::Definition>::Template,
+        > {
+            <Self as $crate::Definition>::factory()
+        }
+
+        $($vis)* fn new_sync<'__beaver>() -> $crate::SyncFactory<
+            '__beaver,
+            $struct,
+            <Self as $crate::Definition>::Transient,
+            <Self as $crate::Definition>::Template,
+        > {
+            <Self as $crate::Definition>::factory()
+        }
+
+        $crate::beaver_shared_sequence!([$($vis)*]);
+    };
+
+    (
+        sequence = shared,
+        parent = $parent:ty,
+        vis = [$($vis:tt)*],
+        struct_type = [$struct:ty],
+    ) => {
+        $($vis)* fn new<'__beaver>() -> $crate::Factory<
+            '__beaver,
+            $struct,
+            <Self as $crate::Definition>::Transient,
+            ::std::sync::Arc<::std::sync::atomic::AtomicU64>,
+            <Self as $crate::Definition>::Template,
+        > {
+            <Self as $crate::Definition>::factory::<::std::sync::Arc<::std::sync::atomic::AtomicU64>>()
+                .with_sequence(<$parent>::shared_sequence())
+        }
+
+        $($vis)* fn new_sync<'__beaver>() -> $crate::Factory<
+            '__beaver,
+            $struct,
+            <Self as $crate::Definition>::Transient,
+            ::std::sync::Arc<::std::sync::atomic::AtomicU64>,
+            <Self as $crate::Definition>::Template,
+        > {
+            Self::new()
+        }
+
+        /// Returns the static sequence of the parent factory, which the factory builds structs with.
+        $($vis)* fn shared_sequence() -> ::std::sync::Arc<::std::sync::atomic::AtomicU64> {
+            <$parent>::shared_sequence()
+        }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! beaver_factory_struct {
+    (
+        vis = [$($vis:tt)*],
+        factory_name = $factory_name:ident,
+        generics = [],
+        lifetimes = [],
+        types = [],
+        where_clause = [$($where_clause:tt)*],
+    ) => {
+        $($vis)* struct $factory_name;
+    };
+
+    (
+        vis = [$($vis:tt)*],
+        factory_name = $factory_name:ident,
+        generics = [$($generics:tt)*],
+        lifetimes = [$($lifetime:lifetime)*],
+        types = [$($type:ident)*],
+        where_clause = [$($where_clause:tt)*],
+    ) => {
+        // A factory never holds values, so the parameters are only used in a function pointer.
+        #[allow(clippy::type_complexity)]
+        $($vis)* struct $factory_name<$($generics)*>(
+            ::std::marker::PhantomData<fn() -> ($(&$lifetime (),)* $($type,)*)>,
+        )
+        where
+            $($where_clause)*;
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! beaver_hook {
+    (after_build, $factory:expr, $hook:expr) => {
+        $factory.with_after_build($hook)
+    };
+
+    (before_create, $factory:expr, $hook:expr) => {
+        $factory.with_before_create($hook)
+    };
+
+    (after_create, $factory:expr, $hook:expr) => {
+        $factory.with_after_create($hook)
+    };
+}
+
+#[cfg(feature = "random")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! beaver_context_rng {
+    ($($field:tt)*) => {
+        fn rng(&self) -> ::std::cell::RefMut<'_, $crate::SeededRng> {
+            self.__beaver_rng.get(__BEAVER_FACTORY, stringify!($($field)*), self.__beaver_seq)
+        }
+    };
+}
+
+#[cfg(not(feature = "random"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! beaver_context_rng {
+    ($($field:tt)*) => {};
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! beaver_field_value {
+    (
+        @context [$fname:tt $(. $fpath:tt)*: $n:pat_param, $t:pat_param => $fvalue:expr],
+        $seq:expr,
+        $target:expr,
+        [$($name:ident = $value:expr),*]
+    ) => {{
+        // A context has references to the fields defined before the field,
+        // and dereferences to the transient attributes, or to the scope in a child factory.
+        #[allow(dead_code, non_camel_case_types)]
+        struct Context<'t, __BeaverTarget, $($name),*> {
+            $($name: $name,)*
+            __beaver_target: &'t __BeaverTarget,
+            __beaver_seq: u64,
+            __beaver_rng: $crate::ContextRng,
+        }
+
+        #[allow(dead_code, non_camel_case_types)]
+        impl<'t, __BeaverTarget, $($name),*> Context<'t, __BeaverTarget, $($name),*> {
+            $crate::beaver_context_rng!($fname $(.$fpath)*);
+        }
+
+        #[allow(non_camel_case_types)]
+        impl<'t, __BeaverTarget, $($name),*> ::std::ops::Deref
+            for Context<'t, __BeaverTarget, $($name),*>
+        {
+            type Target = __BeaverTarget;
+
+            fn deref(&self) -> &Self::Target {
+                self.__beaver_target
             }
         }
+
+        let seq: u64 = $seq;
+        let $n = seq;
+        let $t = &Context {
+            $($name: $value,)*
+            __beaver_target: $target,
+            __beaver_seq: seq,
+            __beaver_rng: ::std::default::Default::default(),
+        };
+        // Temporaries of the generator such as `ctx.rng()` are dropped before the context.
+        let value = $fvalue;
+        value
+    }};
+
+    ([$fname:tt $(. $fpath:tt)*: $n:pat_param, $t:pat_param => $fvalue:expr], $seq:expr, $transient:expr, @values [$($name:ident = $value:ident)*]) => {
+        $crate::beaver_field_value!(
+            @context [$fname $(.$fpath)*: $n, $t => $fvalue],
+            $seq,
+            $transient,
+            [$($name = &$value),*]
+        )
+    };
+
+    ([$fname:tt $(. $fpath:tt)*: $n:pat_param, $t:pat_param => $fvalue:expr], $seq:expr, $transient:expr, [$($name:ident)*], $model:ident) => {
+        $crate::beaver_field_value!(
+            @context [$fname $(.$fpath)*: $n, $t => $fvalue],
+            $seq,
+            $transient,
+            [$($name = &$model.$name),*]
+        )
+    };
+
+    ([$fname:tt $(. $fpath:tt)*: $n:pat_param, $t:pat_param => $fvalue:expr], $seq:expr, $transient:expr, [$($name:ident)*], @scope $model:ident) => {
+        $crate::beaver_field_value!(
+            @context [$fname $(.$fpath)*: $n, $t => $fvalue],
+            $seq,
+            &$crate::Scope::scope($transient, &*$model),
+            [$($name = &$model.$name),*]
+        )
+    };
+
+    ([$fvalue:expr], $seq:expr, $($ignore:tt)*) => {
+        $fvalue($seq)
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! beaver_template {
+    (
+        fields = [$($fields:tt)*],
+        values = [],
+        struct_name = $struct:tt,
+        base = $base:tt,
+        transient = $t:ident,
+        seq = $seq:expr,
+    ) => {
+        $crate::beaver_template! {
+            fields = [$($fields)*],
+            values = [],
+            context = [],
+            struct_name = $struct,
+            base = $base,
+            transient = $t,
+            seq = $seq,
+        }
+    };
+
+    (
+        fields = [],
+        values = [$($name:tt = $value:ident)*],
+        context = $context:tt,
+        struct_name = [[$($enum:tt)*]::$variant:ident],
+        base = [$($base:expr)?],
+        transient = $t:ident,
+        seq = $seq:expr,
+    ) => {
+        $($enum)*::$variant {$($name: $value,)* $(..$base)?}
+    };
+
+    (
+        fields = [],
+        values = [$($name:tt = $value:ident)*],
+        context = $context:tt,
+        struct_name = [$($struct:tt)*],
+        base = [$($base:expr)?],
+        transient = $t:ident,
+        seq = $seq:expr,
+    ) => {
+        $($struct)* {$($name: $value,)* $(..$base)?}
+    };
+
+    (
+        fields = [$fname:ident = $fnames:tt [$($fvalue:tt)*]; $($rest:tt)*],
+        values = [$($values:tt)*],
+        context = [$($name:ident = $value:ident)*],
+        struct_name = $struct:tt,
+        base = $base:tt,
+        transient = $t:ident,
+        seq = $seq:expr,
+    ) => {{
+        // `value` is hygienic, so every field gets its own binding even if a static has the same name.
+        let value = $crate::beaver_field_value!([$($fvalue)*], $seq, $t, @values [$($name = $value)*]);
+        $crate::beaver_template! {
+            fields = [$($rest)*],
+            values = [$($values)* $fname = value],
+            context = [$($name = $value)* $fname = value],
+            struct_name = $struct,
+            base = $base,
+            transient = $t,
+            seq = $seq,
+        }
+    }};
+
+    // Nested paths aren't a part of a struct literal. The generators assign them on every build.
+    (
+        fields = [$fname:ident $(. $fpath:tt)+ = $fnames:tt [$($fvalue:tt)*]; $($rest:tt)*],
+        $($args:tt)*
+    ) => {
+        $crate::beaver_template! {
+            fields = [$($rest)*],
+            $($args)*
+        }
+    };
+
+    (
+        fields = [$index:tt = $fnames:tt [$($fvalue:tt)*]; $($rest:tt)*],
+        values = [$($values:tt)*],
+        context = [$($name:ident = $value:ident)*],
+        struct_name = $struct:tt,
+        base = $base:tt,
+        transient = $t:ident,
+        seq = $seq:expr,
+    ) => {{
+        let value = $crate::beaver_field_value!([$($fvalue)*], $seq, $t, @values [$($name = $value)*]);
+        $crate::beaver_template! {
+            fields = [$($rest)*],
+            values = [$($values)* $index = value],
+            context = [$($name = $value)*],
+            struct_name = $struct,
+            base = $base,
+            transient = $t,
+            seq = $seq,
+        }
+    }};
+}
+
+/// Defines a named sequence shared by every factory in the process.
+///
+/// `sequence!(name, |n| ...)` defines a sequence of `String`, and `sequence!(name: Type, |n| ...)`
+/// defines a sequence of `Type`. [next](fn.next.html) returns the next value of the sequence.
+/// Its counter is atomic, so values are unique across factories and threads.
+/// Only builds consume values. Creating a factory doesn't, so the first struct gets the first value.
+///
+/// # Usage
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct User {
+///     id: u64,
+///     email: String,
+/// }
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Admin {
+///     id: u64,
+///     email: String,
+/// }
+///
+/// beaver::sequence!(email, |n| format!("person{}@example.com", n));
+///
+/// beaver::define! {
+///     UserFactory (User) {
+///         id -> |n| n,
+///         email -> |_| beaver::next(email),
+///     }
+/// }
+///
+/// beaver::define! {
+///     AdminFactory (Admin) {
+///         id -> |n| n,
+///         email -> |_| beaver::next(email),
+///     }
+/// }
+///
+/// let user = UserFactory::new().build(|_| {});
+/// let admin = AdminFactory::new().build(|_| {});
+/// assert_eq!(user.email, "person1@example.com");
+/// assert_eq!(admin.email, "person2@example.com");
+/// ```
+#[macro_export]
+macro_rules! sequence {
+    ($vis:vis $name:ident, $generate:expr $(,)?) => {
+        $crate::sequence!($vis $name: ::std::string::String, $generate);
+    };
+
+    ($vis:vis $name:ident: $type:ty, $generate:expr $(,)?) => {
+        #[allow(non_upper_case_globals)]
+        $vis static $name: &$crate::Sequence<$type> = {
+            static SEQUENCE: $crate::Sequence<$type> = $crate::Sequence::new($generate);
+            &SEQUENCE
+        };
     };
 }
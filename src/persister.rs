@@ -0,0 +1,157 @@
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::hash::Hash;
+
+/// A storage which [create](struct.Factory.html#method.create) saves a built struct to.
+///
+/// `save` returns the saved struct, so values assigned by the storage such as ids
+/// flow back to the caller.
+///
+/// # Usage
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Post {
+///     id: u64,
+///     title: String,
+/// }
+///
+/// beaver::define! {
+///     PostFactory (Post) {
+///         id -> |_| 0,
+///         title -> |n| format!("post-{}", n),
+///     }
+/// }
+///
+/// struct Database {
+///     posts: Vec<Post>,
+/// }
+///
+/// impl beaver::Persister<Post> for Database {
+///     type Error = String;
+///
+///     fn save(&mut self, mut post: Post) -> Result<Post, Self::Error> {
+///         post.id = self.posts.len() as u64 + 100;
+///         self.posts.push(Post {
+///             id: post.id,
+///             title: post.title.clone(),
+///         });
+///         Ok(post)
+///     }
+/// }
+///
+/// let mut db = Database { posts: vec![] };
+/// let post_factory = PostFactory::new();
+/// let post = post_factory.create(&mut db, |_| {}).unwrap();
+/// assert_eq!(post.id, 100);
+/// assert_eq!(db.posts.len(), 1);
+/// ```
+pub trait Persister<T> {
+    /// An error which occurs while saving a struct.
+    type Error;
+
+    /// Saves a struct and returns the saved one.
+    fn save(&mut self, model: T) -> Result<T, Self::Error>;
+}
+
+/// Keeps clones of saved structs in the order they are saved.
+impl<T> Persister<T> for Vec<T>
+where
+    T: Clone,
+{
+    type Error = Infallible;
+
+    fn save(&mut self, model: T) -> Result<T, Self::Error> {
+        self.push(model.clone());
+        Ok(model)
+    }
+}
+
+/// An in-memory persister which keeps clones of saved structs in a `HashMap`.
+///
+/// # Usage
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize, Clone)]
+/// struct Post {
+///     id: u64,
+///     title: String,
+/// }
+///
+/// beaver::define! {
+///     PostFactory (Post) {
+///         id -> |n| n,
+///         title -> |n| format!("post-{}", n),
+///     }
+/// }
+///
+/// let mut persister = beaver::HashMapPersister::new(|post: &Post| post.id);
+/// let post_factory = PostFactory::new();
+/// post_factory.create_list(2, &mut persister, |_| {}).unwrap();
+/// assert_eq!(persister.records[&2].title, "post-2");
+/// ```
+pub struct HashMapPersister<K, T> {
+    pub records: HashMap<K, T>,
+    key: fn(&T) -> K,
+}
+
+impl<K, T> HashMapPersister<K, T> {
+    /// Creates a persister which stores a struct under the key returned by `key`.
+    pub fn new(key: fn(&T) -> K) -> Self {
+        HashMapPersister {
+            records: HashMap::new(),
+            key,
+        }
+    }
+}
+
+impl<K, T> Persister<T> for HashMapPersister<K, T>
+where
+    K: Eq + Hash,
+    T: Clone,
+{
+    type Error = Infallible;
+
+    fn save(&mut self, model: T) -> Result<T, Self::Error> {
+        self.records.insert((self.key)(&model), model.clone());
+        Ok(model)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::persister::{HashMapPersister, Persister};
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Post {
+        id: u64,
+        title: String,
+    }
+
+    #[test]
+    fn test_vec_persister() {
+        let mut persister = vec![];
+        let post = Post {
+            id: 1,
+            title: "post".to_string(),
+        };
+        assert_eq!(persister.save(post.clone()), Ok(post.clone()));
+        assert_eq!(persister, vec![post]);
+    }
+
+    #[test]
+    fn test_hash_map_persister() {
+        let mut persister = HashMapPersister::new(|post: &Post| post.id);
+        for id in [1, 2, 1] {
+            let post = Post {
+                id,
+                title: format!("post-{}", id),
+            };
+            assert_eq!(persister.save(post.clone()), Ok(post));
+        }
+        assert_eq!(persister.records.len(), 2);
+        assert_eq!(persister.records[&1].title, "post-1");
+    }
+}
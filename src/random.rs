@@ -0,0 +1,173 @@
+use lazy_static::lazy_static;
+use rand::SeedableRng;
+use std::cell::{Cell, RefCell, RefMut};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Once;
+
+/// The environment variable which overrides the global seed.
+pub const SEED_ENV: &str = "BEAVER_SEED";
+
+/// The random number generator which `rng()` of a field generator's context returns.
+///
+/// It is portable, so the same seed generates the same values on every platform.
+/// It is available if you enable `random` feature.
+///
+/// # Usage
+/// ```rust
+/// use beaver::rand::Rng;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Product {
+///     id: u64,
+///     price: u64,
+/// }
+///
+/// beaver::define! {
+///     ProductFactory (Product) {
+///         id -> |n| n,
+///         price -> |_, ctx| ctx.rng().gen_range(1..100),
+///     }
+/// }
+///
+/// beaver::set_seed(42);
+/// let product = ProductFactory::build(1);
+/// assert_eq!(ProductFactory::build(1).price, product.price);
+/// ```
+pub type SeededRng = rand_chacha::ChaCha8Rng;
+
+lazy_static! {
+    static ref SEED: AtomicU64 = AtomicU64::new(initial_seed());
+}
+
+static PANIC_HOOK: Once = Once::new();
+
+thread_local! {
+    // Set once a thread generates random values, so that only its panics print the seed.
+    static GENERATED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Returns the global seed which random field values are generated with.
+///
+/// The seed is read from `BEAVER_SEED` environment variable. If it is not set,
+/// a random seed is chosen once per process.
+///
+/// # Panics
+/// Panics if `BEAVER_SEED` is not a `u64`.
+pub fn seed() -> u64 {
+    SEED.load(Ordering::SeqCst)
+}
+
+/// Sets the global seed which random field values are generated with.
+///
+/// # Usage
+/// ```rust
+/// beaver::set_seed(42);
+/// assert_eq!(beaver::seed(), 42);
+/// ```
+pub fn set_seed(seed: u64) {
+    SEED.store(seed, Ordering::SeqCst)
+}
+
+/// Returns a random number generator for a field of a factory.
+///
+/// The generator only depends on the global seed, the factory name, the field name and
+/// the sequence number, so a struct gets the same values however many structs are built before it.
+#[doc(hidden)]
+pub fn rng(factory: &str, field: &str, n: u64) -> SeededRng {
+    GENERATED.with(|generated| generated.set(true));
+    let mut hash = Fnv::new();
+    hash.write(&seed().to_le_bytes());
+    hash.write(factory.as_bytes());
+    hash.write(&[0]);
+    hash.write(field.as_bytes());
+    hash.write(&[0]);
+    hash.write(&n.to_le_bytes());
+    SeededRng::seed_from_u64(hash.0)
+}
+
+/// Prints the global seed when a thread which generated random values panics.
+///
+/// It adds a panic hook once per process, so that a failed test can be replayed with `BEAVER_SEED`.
+/// The previous hook is called before the seed is printed.
+///
+/// # Usage
+/// ```rust
+/// beaver::print_seed_on_panic();
+/// ```
+pub fn print_seed_on_panic() {
+    PANIC_HOOK.call_once(|| {
+        let hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            hook(info);
+            if GENERATED.with(Cell::get) {
+                eprintln!(
+                    "beaver: random values were generated with seed {}. Set {}={} to replay them.",
+                    seed(),
+                    SEED_ENV,
+                    seed()
+                );
+            }
+        }));
+    });
+}
+
+/// The random number generator of a field generator's context, which is seeded on first use.
+#[doc(hidden)]
+#[derive(Default)]
+pub struct ContextRng(RefCell<Option<SeededRng>>);
+
+impl ContextRng {
+    #[doc(hidden)]
+    pub fn get(&self, factory: &str, field: &str, n: u64) -> RefMut<'_, SeededRng> {
+        RefMut::map(self.0.borrow_mut(), |rng| {
+            rng.get_or_insert_with(|| self::rng(factory, field, n))
+        })
+    }
+}
+
+fn initial_seed() -> u64 {
+    match std::env::var(SEED_ENV) {
+        Ok(value) => value.trim().parse().unwrap_or_else(|_| {
+            panic!(
+                "`{}` must be an unsigned integer, but it is `{}`.",
+                SEED_ENV, value
+            )
+        }),
+        Err(_) => rand::random(),
+    }
+}
+
+/// FNV-1a, whose output doesn't change between Rust versions unlike `DefaultHasher`.
+struct Fnv(u64);
+
+impl Fnv {
+    fn new() -> Self {
+        Fnv(0xcbf2_9ce4_8422_2325)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::random::{rng, set_seed};
+    use rand::Rng;
+
+    #[test]
+    fn test_rng() {
+        set_seed(42);
+        let value: u64 = rng("PostFactory", "price", 1).gen();
+        assert_eq!(rng("PostFactory", "price", 1).gen::<u64>(), value);
+        assert_ne!(rng("PostFactory", "price", 2).gen::<u64>(), value);
+        assert_ne!(rng("PostFactory", "views", 1).gen::<u64>(), value);
+        assert_ne!(rng("UserFactory", "price", 1).gen::<u64>(), value);
+        set_seed(43);
+        assert_ne!(rng("PostFactory", "price", 1).gen::<u64>(), value);
+    }
+}
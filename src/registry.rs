@@ -0,0 +1,191 @@
+use crate::counter::Counter;
+use crate::error::Error;
+use crate::factory::SyncFactory;
+use crate::global;
+use crate::template::Template;
+use lazy_static::lazy_static;
+use std::any::{type_name, Any};
+use std::collections::HashMap;
+use std::sync::atomic::AtomicU64;
+use std::sync::{Arc, RwLock};
+
+type BuildFunc<T> = Box<dyn Fn(&dyn Fn(&mut T)) -> Result<T, Error> + Send + Sync>;
+type BuildValueFunc = Box<dyn Fn() -> Result<serde_json::Value, Error> + Send + Sync>;
+
+struct Entry {
+    build: Box<dyn Any + Send + Sync>,
+    build_value: BuildValueFunc,
+    /// The sequence and the stub sequence of the factory, which are unregistered when it's replaced.
+    counters: [&'static AtomicU64; 2],
+}
+
+lazy_static! {
+    static ref REGISTRY: RwLock<HashMap<&'static str, Arc<Entry>>> = RwLock::new(HashMap::new());
+}
+
+/// Registers a factory with a name, so that [build](fn.build.html) and
+/// [build_value](fn.build_value.html) can build structs by the name.
+///
+/// A factory with the [Cloned](struct.Cloned.html) template can also be registered,
+/// but [build_value](fn.build_value.html) returns an error for it since its struct isn't `Serialize`.
+///
+/// A registered factory is leaked to live until the end of the program, so that the registry can lend
+/// it to other threads without locking. A factory replaced by registering another one with the same name
+/// is leaked as well, and its counters are no longer reset by [reset_all](fn.reset_all.html).
+/// Register factories once, e.g. in a setup function, rather than on every build.
+///
+/// # Usage
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Post {
+///     id: u64,
+///     title: String,
+/// }
+///
+/// beaver::define! {
+///     PostFactory (Post) {
+///         id -> |n| n,
+///         title -> |n| format!("post-{}", n),
+///     }
+/// }
+///
+/// beaver::register("post", PostFactory::new_sync());
+///
+/// let post = beaver::build::<Post>("post", |post| post.title = "foo".to_string());
+/// assert_eq!(post.title, "foo");
+///
+/// let value = beaver::build_value("post");
+/// assert_eq!(value["title"], "post-2");
+/// ```
+pub fn register<T, Tr, M>(name: &'static str, factory: SyncFactory<'static, T, Tr, M>)
+where
+    T: Send + Sync + 'static,
+    Tr: Default + 'static,
+    M: Template<'static, T> + Send + Sync + 'static,
+{
+    let factory: &'static SyncFactory<'static, T, Tr, M> = Box::leak(Box::new(factory));
+    global::register_counter(&factory.sequence, factory.sequence.get());
+    global::register_counter(&factory.stub_sequence, factory.stub_sequence.get());
+    let build: BuildFunc<T> = Box::new(move |f| factory.try_build(f));
+    let entry = Entry {
+        build: Box::new(build),
+        build_value: Box::new(move || {
+            M::to_value(factory.try_build(|_| {})?)
+                .unwrap_or_else(|| Err(Error::NotSerializable(name.to_string())))
+        }),
+        counters: [&factory.sequence, &factory.stub_sequence],
+    };
+    let replaced = REGISTRY
+        .write()
+        .unwrap_or_else(|err| err.into_inner())
+        .insert(name, Arc::new(entry));
+    if let Some(replaced) = replaced {
+        for counter in replaced.counters {
+            global::unregister_counter(counter);
+        }
+    }
+}
+
+/// Returns the names of the registered factories in alphabetical order.
+pub fn registered() -> Vec<&'static str> {
+    let mut names: Vec<_> = REGISTRY
+        .read()
+        .unwrap_or_else(|err| err.into_inner())
+        .keys()
+        .copied()
+        .collect();
+    names.sort_unstable();
+    names
+}
+
+/// Builds a struct from the factory registered with `name`.
+///
+/// # Panics
+/// Panics if no factory is registered with `name`, or the factory doesn't build `T`.
+pub fn build<T>(name: &str, f: impl Fn(&mut T)) -> T
+where
+    T: 'static,
+{
+    try_build(name, f).unwrap_or_else(|err| panic!("{}", err))
+}
+
+/// Builds a struct from the factory registered with `name`, returning an error instead of panicking.
+pub fn try_build<T>(name: &str, f: impl Fn(&mut T)) -> Result<T, Error>
+where
+    T: 'static,
+{
+    let entry = find(name)?;
+    let build = entry
+        .build
+        .downcast_ref::<BuildFunc<T>>()
+        .ok_or_else(|| Error::FactoryType {
+            name: name.to_string(),
+            expected: type_name::<T>(),
+        })?;
+    build(&f)
+}
+
+/// Builds a struct from the factory registered with `name` and returns it as JSON.
+///
+/// # Panics
+/// Panics if no factory is registered with `name`, or the factory keeps structs with `clone`.
+pub fn build_value(name: &str) -> serde_json::Value {
+    try_build_value(name).unwrap_or_else(|err| panic!("{}", err))
+}
+
+/// Builds a struct from the factory registered with `name` and returns it as JSON,
+/// returning an error instead of panicking.
+pub fn try_build_value(name: &str) -> Result<serde_json::Value, Error> {
+    (find(name)?.build_value)()
+}
+
+fn find(name: &str) -> Result<Arc<Entry>, Error> {
+    // The lock is released before building, so factories can build other registered factories.
+    let entry = REGISTRY
+        .read()
+        .unwrap_or_else(|err| err.into_inner())
+        .get(name)
+        .cloned();
+    entry.ok_or_else(|| Error::UnknownFactory {
+        name: name.to_string(),
+        registered: registered().into_iter().map(String::from).collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::factory::{new_with_counter, SyncFactory, SyncGenFunc};
+    use crate::global;
+    use crate::registry::{register, REGISTRY};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct Post {
+        id: u64,
+    }
+
+    fn factory() -> SyncFactory<'static, Post> {
+        let suite: SyncGenFunc<Post> = Box::new(|post, n, _| post.id = n);
+        new_with_counter(Post { id: 0 }, suite)
+    }
+
+    #[test]
+    fn test_register_replaces_counters() {
+        register("registry_test_post", factory());
+        let counters = REGISTRY.read().unwrap()["registry_test_post"].counters;
+        assert!(counters
+            .iter()
+            .all(|counter| global::is_registered(counter)));
+
+        register("registry_test_post", factory());
+        assert!(!counters
+            .iter()
+            .any(|counter| global::is_registered(counter)));
+        let counters = REGISTRY.read().unwrap()["registry_test_post"].counters;
+        assert!(counters
+            .iter()
+            .all(|counter| global::is_registered(counter)));
+    }
+}
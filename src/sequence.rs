@@ -0,0 +1,108 @@
+use crate::counter::Counter;
+use crate::error::Error;
+use crate::global;
+use std::cell::Cell;
+use std::sync::atomic::AtomicU64;
+use std::sync::Once;
+
+thread_local! {
+    // Set while a factory builds its template, which must not consume values of named sequences.
+    static PEEKING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// A named sequence shared by every factory in the process.
+///
+/// It is defined by [sequence!](macro.sequence.html) and advanced by [next](fn.next.html).
+/// Its counter is atomic, so values are unique across factories and threads.
+pub struct Sequence<T> {
+    counter: AtomicU64,
+    generate: fn(u64) -> T,
+    registered: Once,
+}
+
+impl<T> Sequence<T> {
+    #[doc(hidden)]
+    pub const fn new(generate: fn(u64) -> T) -> Self {
+        Sequence {
+            counter: AtomicU64::new(1),
+            generate,
+            registered: Once::new(),
+        }
+    }
+
+    /// Returns the next value of the sequence.
+    ///
+    /// # Panics
+    /// Panics if the sequence number overflows `u64`.
+    pub fn next(&'static self) -> T {
+        // A sequence is registered on first use, so that `reset_all` and `restore` can reach it.
+        self.registered
+            .call_once(|| global::register_counter(&self.counter, 1));
+        let n = if PEEKING.with(Cell::get) {
+            self.counter.get()
+        } else {
+            self.counter
+                .next()
+                .unwrap_or_else(|| panic!("{}", Error::SequenceOverflow))
+        };
+        (self.generate)(n)
+    }
+
+    /// Returns the sequence number which the next value is generated with.
+    pub fn get(&self) -> u64 {
+        self.counter.get()
+    }
+
+    /// Sets the sequence number which the next value is generated with.
+    pub fn set(&self, n: u64) {
+        self.counter.set(n)
+    }
+}
+
+/// Returns the next value of a named sequence defined by [sequence!](macro.sequence.html).
+pub fn next<T>(sequence: &'static Sequence<T>) -> T {
+    sequence.next()
+}
+
+/// Runs `f` with named sequences which return their next values without consuming them.
+///
+/// A factory builds its template with it, so that only real builds advance named sequences.
+#[doc(hidden)]
+pub fn peek<R>(f: impl FnOnce() -> R) -> R {
+    struct Restore(bool);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            PEEKING.with(|peeking| peeking.set(self.0));
+        }
+    }
+
+    let _restore = Restore(PEEKING.with(|peeking| peeking.replace(true)));
+    f()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sequence::{next, peek, Sequence};
+
+    static SEQUENCE: Sequence<String> = Sequence::new(|n| format!("user-{}", n));
+
+    #[test]
+    fn test_sequence() {
+        assert_eq!(next(&SEQUENCE), "user-1");
+        assert_eq!(SEQUENCE.next(), "user-2");
+        assert_eq!(SEQUENCE.get(), 3);
+        SEQUENCE.set(10);
+        assert_eq!(next(&SEQUENCE), "user-10");
+    }
+
+    #[test]
+    fn test_peek() {
+        static PEEKED: Sequence<u64> = Sequence::new(|n| n);
+
+        assert_eq!(peek(|| (next(&PEEKED), next(&PEEKED))), (1, 1));
+        assert_eq!(next(&PEEKED), 1);
+        assert_eq!(peek(|| next(&PEEKED)), 2);
+        assert_eq!(next(&PEEKED), 2);
+    }
+}
@@ -0,0 +1,87 @@
+use std::convert::TryFrom;
+use std::time::SystemTime;
+
+/// The first stub id of [build_stubbed](struct.Factory.html#method.build_stubbed).
+pub const STUB_ID_START: u64 = 1001;
+
+/// A value which a field listed without a generator in a `stub` block is set to.
+///
+/// `id`, `created_at` and `updated_at` are stubbed by their names without this trait,
+/// so it is needed for other fields or to override them.
+///
+/// Integers are set to the stub id, and timestamps such as `SystemTime` are set to the current time.
+/// If you enable `chrono` feature, `NaiveDateTime` and `DateTime<Utc>` are also supported.
+pub trait Stub {
+    /// Returns a value for a stubbed struct whose stub id is `id`.
+    fn stub(id: u64) -> Self;
+}
+
+macro_rules! impl_stub_for_integers {
+    ($($ty:ty),*) => {
+        $(
+            impl Stub for $ty {
+                fn stub(id: u64) -> Self {
+                    <$ty>::try_from(id).unwrap_or_else(|_| {
+                        panic!("Stub id `{}` overflowed `{}`.", id, stringify!($ty))
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_stub_for_integers!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl Stub for SystemTime {
+    fn stub(_: u64) -> Self {
+        SystemTime::now()
+    }
+}
+
+impl<T> Stub for Option<T>
+where
+    T: Stub,
+{
+    fn stub(id: u64) -> Self {
+        Some(T::stub(id))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl Stub for chrono::NaiveDateTime {
+    fn stub(_: u64) -> Self {
+        chrono::Utc::now().naive_utc()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl Stub for chrono::DateTime<chrono::Utc> {
+    fn stub(_: u64) -> Self {
+        chrono::Utc::now()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::stub::Stub;
+    use std::time::SystemTime;
+
+    #[test]
+    fn test_stub() {
+        assert_eq!(u64::stub(1001), 1001);
+        assert_eq!(i32::stub(1001), 1001);
+        assert_eq!(u8::stub(100), 100);
+        assert_eq!(i8::stub(100), 100);
+        assert_eq!(i16::stub(1001), 1001);
+        assert_eq!(Option::<u64>::stub(1001), Some(1001));
+        assert!(SystemTime::stub(1001) <= SystemTime::now());
+    }
+
+    #[test]
+    fn test_stub_with_overflow() {
+        let result1 = std::panic::catch_unwind(|| u16::stub(70_000));
+        let result2 = std::panic::catch_unwind(|| u8::stub(1001));
+        assert!(result1.is_err());
+        assert!(result2.is_err());
+    }
+}
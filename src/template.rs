@@ -0,0 +1,212 @@
+use crate::error::Error;
+use serde::{Deserialize, Serialize};
+use std::time::SystemTime;
+
+/// Fields which [build_stubbed](struct.Factory.html#method.build_stubbed) sets by their names.
+const STUB_ID_FIELD: &str = "id";
+const STUB_TIME_FIELDS: [&str; 2] = ["created_at", "updated_at"];
+
+/// A template from which [Factory](struct.Factory.html) instantiates a struct.
+///
+/// - `String` keeps a struct as JSON. It needs both of `Serialize` and `Deserialize`.
+/// - [Cloned](struct.Cloned.html) keeps a struct as it is and clones it. It needs `Clone`.
+pub trait Template<'a, T> {
+    /// Creates a template from a struct.
+    fn new(model: T) -> Result<Self, Error>
+    where
+        Self: Sized;
+
+    /// Instantiates a struct from the template.
+    fn instantiate(&'a self) -> Result<T, Error>;
+
+    /// Converts a struct into JSON, or returns `None` if the template doesn't need `Serialize`.
+    fn to_value(_model: T) -> Option<Result<serde_json::Value, Error>>
+    where
+        Self: Sized,
+    {
+        None
+    }
+
+    /// Sets `id`, `created_at` and `updated_at` of a stubbed struct if it has them.
+    ///
+    /// A template which can't inspect the fields of a struct returns it as it is.
+    fn stub(model: T, _id: u64) -> Result<T, Error>
+    where
+        Self: Sized,
+    {
+        Ok(model)
+    }
+}
+
+impl<'a, T> Template<'a, T> for String
+where
+    T: Serialize + Deserialize<'a>,
+{
+    fn new(model: T) -> Result<Self, Error> {
+        serde_json::to_string(&model).map_err(Error::Serialization)
+    }
+
+    fn instantiate(&'a self) -> Result<T, Error> {
+        let deserializer = &mut serde_json::Deserializer::from_str(self.as_str());
+        serde_path_to_error::deserialize(deserializer).map_err(|err| Error::Deserialization {
+            json: self.clone(),
+            path: err.path().to_string(),
+            source: err.into_inner(),
+        })
+    }
+
+    fn to_value(model: T) -> Option<Result<serde_json::Value, Error>> {
+        Some(serde_json::to_value(model).map_err(Error::Serialization))
+    }
+
+    fn stub(model: T, id: u64) -> Result<T, Error> {
+        let mut value = serde_json::to_value(&model).map_err(Error::Serialization)?;
+        if !value.is_object() {
+            return Ok(model);
+        }
+        // A field is only stubbed if the struct accepts one of the candidates,
+        // so an id such as a UUID string keeps its generated value.
+        let now = SystemTime::now();
+        let mut candidates = vec![(STUB_ID_FIELD, vec![serde_json::Value::from(id)])];
+        for field in STUB_TIME_FIELDS.iter() {
+            candidates.push((field, time_candidates(now)));
+        }
+        let mut stubbed = false;
+        for (field, values) in candidates {
+            if value.get(field).is_none() {
+                continue;
+            }
+            for candidate in values {
+                let mut stub = value.clone();
+                stub[field] = candidate;
+                if T::deserialize(stub.clone()).is_ok() {
+                    value = stub;
+                    stubbed = true;
+                    break;
+                }
+            }
+        }
+        if !stubbed {
+            return Ok(model);
+        }
+        T::deserialize(value.clone()).map_err(|source| Error::Deserialization {
+            json: value.to_string(),
+            path: String::new(),
+            source,
+        })
+    }
+}
+
+/// Returns JSON representations of `now` which timestamp types are deserialized from.
+fn time_candidates(now: SystemTime) -> Vec<serde_json::Value> {
+    #[allow(unused_mut)]
+    let mut candidates = vec![serde_json::to_value(now).unwrap_or_default()];
+    #[cfg(feature = "chrono")]
+    {
+        let now = chrono::DateTime::<chrono::Utc>::from(now);
+        candidates.push(serde_json::Value::from(
+            now.naive_utc().format("%Y-%m-%dT%H:%M:%S%.f").to_string(),
+        ));
+        candidates.push(serde_json::Value::from(now.to_rfc3339()));
+    }
+    candidates
+}
+
+/// A template which clones a struct instead of serializing it.
+///
+/// If you want to use it, you can put `clone` after a struct name in [define](macro.define.html).
+///
+/// # Usage
+/// ```rust
+/// use std::sync::Arc;
+///
+/// // `Post` doesn't need `Serialize` and `Deserialize`.
+/// #[derive(Clone)]
+/// struct Post {
+///     id: u64,
+///     title: Arc<String>,
+/// }
+///
+/// beaver::define! {
+///     PostFactory (Post) clone {
+///         id -> |n| n,
+///         title -> |n| Arc::new(format!("post-{}", n)),
+///     }
+/// }
+///
+/// let post_factory = PostFactory::new();
+/// let post = post_factory.build(|_| {});
+/// assert_eq!(*post.title, "post-1");
+/// ```
+pub struct Cloned<T>(pub T);
+
+impl<'a, T> Template<'a, T> for Cloned<T>
+where
+    T: Clone,
+{
+    fn new(model: T) -> Result<Self, Error> {
+        Ok(Cloned(model))
+    }
+
+    fn instantiate(&'a self) -> Result<T, Error> {
+        Ok(self.0.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::template::{Cloned, Template};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+    struct Post {
+        id: u64,
+        title: String,
+    }
+
+    #[test]
+    fn test_json_template() {
+        let template: String = Template::new(Post {
+            id: 1,
+            title: "post".to_string(),
+        })
+        .unwrap();
+        assert_eq!(template, r#"{"id":1,"title":"post"}"#);
+        assert_eq!(
+            Template::<Post>::instantiate(&template).unwrap(),
+            Post {
+                id: 1,
+                title: "post".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_json_template_with_invalid_json() {
+        let template = r#"{"id":1,"title":2}"#.to_string();
+        match Template::<Post>::instantiate(&template) {
+            Err(Error::Deserialization { json, path, .. }) => {
+                assert_eq!(json, template);
+                assert_eq!(path, "title");
+            }
+            _ => panic!("expected a deserialization error"),
+        }
+    }
+
+    #[test]
+    fn test_cloned_template() {
+        let template = Cloned::new(Post {
+            id: 1,
+            title: "post".to_string(),
+        })
+        .unwrap();
+        assert_eq!(
+            template.instantiate().unwrap(),
+            Post {
+                id: 1,
+                title: "post".to_string()
+            }
+        );
+    }
+}
@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Post {
+    id: u64,
+    title: String,
+    approved: bool,
+    checksum: usize,
+    tags: Vec<Tag>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Tag {
+    id: u64,
+    post_id: u64,
+}
+
+beaver::define! {
+    PostFactory (Post) {
+        id -> |n| n,
+        title -> |n| format!("post-{}", n),
+        approved -> |_| false,
+        checksum -> |_| 0,
+        tags -> |n| TagFactory::build_list(2, n),
+        after_build |post, _| {
+            post.checksum = post.title.len();
+        }
+        after_build |post, n| {
+            for tag in post.tags.iter_mut() {
+                tag.post_id = post.id;
+            }
+            post.title = format!("{} ({})", post.title, n);
+        }
+        trait approved {
+            approved -> |_| true,
+        }
+    }
+}
+
+beaver::define! {
+    TagFactory (Tag) {
+        id -> |n| n,
+        post_id -> |_| 0,
+    }
+}
+
+#[test]
+fn is_runs_after_build_hooks() {
+    let post_factory = PostFactory::new();
+
+    let post1 = post_factory.build(|_| {});
+    let post2 = post_factory.build_with_traits(&["approved"], |post| {
+        post.id = 10;
+        post.title = "beaver".to_string();
+    });
+    let posts = post_factory.build_list(2, |_| {});
+
+    assert_eq!(
+        post1,
+        Post {
+            id: 1,
+            title: "post-1 (1)".to_string(),
+            approved: false,
+            checksum: 6,
+            tags: vec![Tag { id: 1, post_id: 1 }, Tag { id: 2, post_id: 1 }],
+        }
+    );
+    assert_eq!(
+        post2,
+        Post {
+            id: 10,
+            title: "beaver (2)".to_string(),
+            approved: true,
+            checksum: 6,
+            tags: vec![Tag { id: 3, post_id: 10 }, Tag { id: 4, post_id: 10 }],
+        }
+    );
+    assert_eq!(posts[0].title, "post-3 (3)");
+    assert_eq!(posts[1].tags[0].post_id, 4);
+}
+
+#[test]
+fn is_not_running_after_build_hooks_in_the_template() {
+    let post_factory = PostFactory::new();
+
+    assert_eq!(
+        post_factory.model,
+        r#"{"id":1,"title":"post-1","approved":false,"checksum":0,"tags":[{"id":1,"post_id":0},{"id":2,"post_id":0}]}"#
+    );
+}
@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Post {
+    id: u64,
+    title: String,
+    file: File,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct File {
+    id: u64,
+    path: String,
+}
+
+beaver::define! {
+    PostFactory (Post) {
+        id -> |n| n,
+        title -> |n| format!("post-{}", n),
+        file -> |n| FileFactory::build(n),
+    }
+}
+
+beaver::define! {
+    FileFactory (File) {
+        id -> |n| n,
+        path -> |n| format!("path/to/file-{}", n),
+    }
+}
+
+#[test]
+fn is_returns_attributes() {
+    let post_factory = PostFactory::new();
+
+    let attributes1 = post_factory.attributes_for(|_| {});
+    let attributes2 = post_factory.attributes_for(|post| post.title = "beaver".to_string());
+    let attributes3 = post_factory.attributes_map_for(|_| {});
+    let list = post_factory.attributes_for_list(2, |post| post.file.path = "".to_string());
+
+    assert_eq!(
+        attributes1,
+        json!({
+            "id": 1,
+            "title": "post-1",
+            "file": {"id": 1, "path": "path/to/file-1"},
+        })
+    );
+    assert_eq!(attributes2["id"], 2);
+    assert_eq!(attributes2["title"], "beaver");
+    assert_eq!(attributes3["id"], 3);
+    assert_eq!(attributes3.len(), 3);
+    assert_eq!(
+        list,
+        vec![
+            json!({"id": 4, "title": "post-4", "file": {"id": 4, "path": ""}}),
+            json!({"id": 5, "title": "post-5", "file": {"id": 5, "path": ""}}),
+        ]
+    );
+}
@@ -0,0 +1,196 @@
+use serde::{Deserialize, Serialize};
+use std::time::SystemTime;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Post {
+    id: u64,
+    title: String,
+    created_at: Option<SystemTime>,
+    updated_at: Option<SystemTime>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Tag {
+    id: i64,
+    name: String,
+}
+
+beaver::define! {
+    PostFactory (Post) {
+        id -> |_| 0,
+        title -> |n| format!("post-{}", n),
+        created_at -> |_| None,
+        updated_at -> |_| None,
+        stub {
+            id,
+            created_at,
+            updated_at -> |_, post| *post.created_at,
+        }
+    }
+}
+
+beaver::define! {
+    TagFactory (Tag) {
+        id -> |n| n as i64,
+        name -> |n| format!("tag-{}", n),
+        stub (100) {
+            id,
+            name -> |id| format!("stubbed-tag-{}", id),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Comment {
+    id: u64,
+    body: String,
+    created_at: SystemTime,
+    updated_at: Option<SystemTime>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Session {
+    id: String,
+    user_id: u8,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Draft {
+    id: u64,
+}
+
+beaver::define! {
+    CommentFactory (Comment) {
+        id -> |n| n,
+        body -> |n| format!("comment-{}", n),
+        created_at -> |_| SystemTime::UNIX_EPOCH,
+        updated_at -> |_| None,
+    }
+}
+
+beaver::define! {
+    SessionFactory (Session) {
+        id -> |n| format!("session-{}", n),
+        user_id -> |_| 0,
+        stub (1) {
+            user_id,
+        }
+    }
+}
+
+beaver::define! {
+    DraftFactory (Draft) clone {
+        id -> |n| n,
+    }
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_fields {
+    use chrono::{DateTime, NaiveDateTime, Utc};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    pub struct Event {
+        pub id: Option<i64>,
+        pub created_at: NaiveDateTime,
+        pub updated_at: Option<DateTime<Utc>>,
+    }
+
+    beaver::define! {
+        pub EventFactory (Event) {
+            id -> |_| None,
+            created_at -> |_| DateTime::UNIX_EPOCH.naive_utc(),
+            updated_at -> |_| None,
+        }
+    }
+}
+
+#[test]
+fn is_builds_stubbed_struct() {
+    let post_factory = PostFactory::new();
+
+    let post1 = post_factory.build(|_| {});
+    let post2 = post_factory.build_stubbed(|_| {});
+    let posts = post_factory.build_stubbed_list(2, |post| post.title = "foo".to_string());
+
+    assert_eq!(post1.id, 0);
+    assert_eq!(post1.created_at, None);
+    assert_eq!(post2.id, 1001);
+    assert_eq!(post2.title, "post-2");
+    assert!(post2.created_at.is_some());
+    assert_eq!(post2.created_at, post2.updated_at);
+    assert_eq!(
+        posts
+            .iter()
+            .map(|post| (post.id, post.title.as_str()))
+            .collect::<Vec<_>>(),
+        vec![(1002, "foo"), (1003, "foo")]
+    );
+}
+
+#[test]
+fn is_builds_stubbed_struct_with_stub_id_range() {
+    let tag_factory = TagFactory::new();
+
+    let tag1 = tag_factory.build_stubbed(|_| {});
+    let tag2 = tag_factory.build(|_| {});
+    let tag3 = tag_factory.build_stubbed(|_| {});
+
+    assert_eq!(
+        tag1,
+        Tag {
+            id: 100,
+            name: "stubbed-tag-100".to_string()
+        }
+    );
+    assert_eq!(
+        tag2,
+        Tag {
+            id: 2,
+            name: "tag-2".to_string()
+        }
+    );
+    assert_eq!(tag3.id, 101);
+}
+
+#[test]
+fn is_stubs_conventional_fields_by_their_names() {
+    let comment_factory = CommentFactory::new();
+
+    let comment1 = comment_factory.build(|_| {});
+    let comment2 = comment_factory.build_stubbed(|_| {});
+
+    assert_eq!(comment1.id, 1);
+    assert_eq!(comment1.created_at, SystemTime::UNIX_EPOCH);
+    assert_eq!(comment2.id, 1001);
+    assert_eq!(comment2.body, "comment-2");
+    assert!(comment2.created_at > SystemTime::UNIX_EPOCH);
+    assert_eq!(comment2.updated_at, Some(comment2.created_at));
+}
+
+#[test]
+fn is_keeps_fields_which_stubs_do_not_fit() {
+    let session = SessionFactory::new().build_stubbed(|_| {});
+    let draft = DraftFactory::new().build_stubbed(|_| {});
+
+    assert_eq!(
+        session,
+        Session {
+            id: "session-1".to_string(),
+            user_id: 1,
+        }
+    );
+    assert_eq!(draft, Draft { id: 1 });
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn is_stubs_chrono_timestamps() {
+    use chrono::DateTime;
+
+    let event = chrono_fields::EventFactory::new().build_stubbed(|_| {});
+
+    assert_eq!(event.id, Some(1001));
+    assert!(event.created_at > DateTime::UNIX_EPOCH.naive_utc());
+    assert!(event.updated_at.is_some());
+}
@@ -0,0 +1,180 @@
+use serde::{Deserialize, Serialize};
+use std::cell::Cell;
+use std::rc::Rc;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct Post {
+    id: u64,
+    title: String,
+    slug: String,
+    approved: bool,
+    log: Vec<String>,
+}
+
+beaver::define! {
+    pub PostFactory (Post) {
+        transient PostTransient {
+            prefix: &'static str = "post",
+        }
+        id -> |n| n,
+        title -> |n, t| format!("{}-{}", t.prefix, n),
+        slug -> |_, post| post.title.to_lowercase(),
+        approved -> |_| false,
+        log -> |_| vec![],
+        after_build |post, _| {
+            post.log.push("post".to_string());
+        }
+        trait draft {
+            title -> |n| format!("draft-{}", n),
+        }
+        trait shouting {
+            title -> |n| format!("POST-{}", n),
+        }
+        stub (500) {
+            title -> |id| format!("stubbed-post-{}", id),
+        }
+    }
+}
+
+beaver::define! {
+    pub PublishedPostFactory (Post) : PostFactory {
+        approved -> |_| true,
+        slug -> |_, post| format!("published-{}", post.approved),
+        after_build |post, _| {
+            post.log.push("published".to_string());
+        }
+        trait shouting {
+            title -> |n| format!("PUBLISHED-{}!", n),
+        }
+    }
+}
+
+beaver::define! {
+    pub SluggedPostFactory (Post) : PostFactory {
+        slug -> |_, post| post.title.clone(),
+        log -> |_, post| vec![format!("{}:{}", post.prefix, post.id)],
+        stub {
+            slug -> |id| format!("stubbed-slug-{}", id),
+        }
+    }
+}
+
+beaver::define! {
+    pub DraftPostFactory (Post) : PostFactory {
+        sequence shared,
+        title -> |n| format!("draft-{}", n),
+    }
+}
+
+beaver::define! {
+    pub OwnPostFactory (Post) : PostFactory {
+        sequence own,
+        approved -> |_| true,
+    }
+}
+
+mod child {
+    use crate::{Post, PublishedPostFactory};
+
+    beaver::define! {
+        pub FeaturedPostFactory (Post) : PublishedPostFactory {
+            title -> |n, t| format!("featured-{}-{}", t.prefix, n),
+        }
+    }
+}
+
+#[test]
+fn is_builds_struct_from_child_factory() {
+    let published_post_factory = PublishedPostFactory::new();
+
+    let post1 = published_post_factory.build(|_| {});
+    let post2 = published_post_factory.build_with_transient(|t| t.prefix = "article", |_| {});
+    let post3 = published_post_factory.build_with_traits(&["draft"], |_| {});
+    let post4 = published_post_factory.build_with_traits(&["shouting"], |_| {});
+
+    assert_eq!(
+        post1,
+        Post {
+            id: 1,
+            title: "post-1".to_string(),
+            slug: "published-true".to_string(),
+            approved: true,
+            log: vec!["post".to_string(), "published".to_string()],
+        }
+    );
+    assert_eq!(post2.title, "article-2");
+    assert_eq!(post3.title, "draft-3");
+    assert_eq!(post4.title, "PUBLISHED-4!");
+    assert!(!PostFactory::new().build(|_| {}).approved);
+}
+
+#[test]
+fn is_builds_struct_from_grandchild_factory() {
+    let featured_post_factory = child::FeaturedPostFactory::new();
+
+    let post = featured_post_factory.build(|_| {});
+
+    assert_eq!(post.title, "featured-post-1");
+    assert!(post.approved);
+    assert_eq!(child::FeaturedPostFactory::build(5).id, 5);
+}
+
+#[test]
+fn is_shares_sequence_with_parent_factory() {
+    let sequence = Rc::new(Cell::new(1));
+    let post_factory = PostFactory::new().with_sequence(sequence.clone());
+    let published_post_factory = PublishedPostFactory::new().with_sequence(sequence);
+    let own_post_factory = PublishedPostFactory::new();
+
+    let post1 = post_factory.build(|_| {});
+    let post2 = published_post_factory.build(|_| {});
+    let post3 = post_factory.build(|_| {});
+    let post4 = own_post_factory.build(|_| {});
+
+    assert_eq!(
+        vec![post1.id, post2.id, post3.id, post4.id],
+        vec![1, 2, 3, 1]
+    );
+}
+
+#[test]
+fn is_shares_sequence_with_parent_factory_by_definition() {
+    let post_factory = PostFactory::new_sync().with_sequence(PostFactory::shared_sequence());
+    let draft_post_factory = DraftPostFactory::new();
+    let own_post_factory = OwnPostFactory::new();
+
+    let post1 = post_factory.build(|_| {});
+    let post2 = draft_post_factory.build(|_| {});
+    let post3 = DraftPostFactory::new_sync().build(|_| {});
+    let post4 = own_post_factory.build(|_| {});
+
+    assert_eq!(post1.id, 1);
+    assert_eq!(post2.id, 2);
+    assert_eq!(post2.title, "draft-2");
+    assert_eq!(post3.id, 3);
+    assert_eq!(post4.id, 1);
+    assert!(post4.approved);
+}
+
+#[test]
+fn is_reads_parent_fields_in_child_factory() {
+    let slugged_post_factory = SluggedPostFactory::new();
+
+    let post1 = slugged_post_factory.build(|_| {});
+    let post2 = slugged_post_factory.build_with_transient(|t| t.prefix = "article", |_| {});
+
+    assert_eq!(post1.title, "post-1");
+    assert_eq!(post1.slug, "post-1");
+    assert_eq!(post1.log, vec!["post:1".to_string(), "post".to_string()]);
+    assert_eq!(post2.slug, "article-2");
+    assert_eq!(post2.log[0], "article:2");
+}
+
+#[test]
+fn is_applies_stub_block_after_parent_one() {
+    let post = SluggedPostFactory::new().build_stubbed(|_| {});
+
+    assert_eq!(post.id, 500);
+    assert_eq!(post.title, "stubbed-post-500");
+    assert_eq!(post.slug, "stubbed-slug-500");
+}
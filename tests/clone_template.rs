@@ -0,0 +1,73 @@
+use std::rc::Rc;
+
+#[derive(Clone, Debug, PartialEq)]
+struct PostId(u64);
+
+// `Post` doesn't implement `Serialize` and `Deserialize`.
+#[derive(Clone, Debug, PartialEq)]
+struct Post {
+    id: PostId,
+    title: String,
+    approved: bool,
+    file: File,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct File {
+    id: u64,
+    path: Rc<str>,
+}
+
+beaver::define! {
+    PostFactory (Post) clone {
+        id -> |n| PostId(n),
+        title -> |n| format!("post-{}", n),
+        approved -> |_| false,
+        file -> |n| FileFactory::build(n),
+        trait approved {
+            approved -> |_| true,
+        }
+    }
+}
+
+beaver::define! {
+    FileFactory (File) clone {
+        id -> |n| n,
+        path -> |n| Rc::from(format!("path/to/file-{}", n)),
+    }
+}
+
+#[test]
+fn is_builds_struct_with_cloned_template() {
+    let post_factory = PostFactory::new();
+
+    let post1 = post_factory.build(|_| {});
+    let post2 =
+        post_factory.build_with_traits(&["approved"], |post| post.title = "foo".to_string());
+
+    assert_eq!(
+        post1,
+        Post {
+            id: PostId(1),
+            title: "post-1".to_string(),
+            approved: false,
+            file: File {
+                id: 1,
+                path: Rc::from("path/to/file-1"),
+            },
+        }
+    );
+    assert_eq!(
+        post2,
+        Post {
+            id: PostId(2),
+            title: "foo".to_string(),
+            approved: true,
+            file: File {
+                id: 2,
+                path: Rc::from("path/to/file-2"),
+            },
+        }
+    );
+    assert_eq!(post_factory.model.0, post1);
+}
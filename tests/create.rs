@@ -0,0 +1,134 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+struct Post {
+    id: u64,
+    title: String,
+    log: Vec<String>,
+}
+
+beaver::define! {
+    PostFactory (Post) {
+        id -> |_| 0,
+        title -> |n| format!("post-{}", n),
+        log -> |_| vec![],
+        after_create |post, n| {
+            post.log.push(format!("after_create-{}", n));
+        }
+        before_create |post, _| {
+            post.log.push("before_create".to_string());
+        }
+        after_build |post, _| {
+            post.log.push("after_build".to_string());
+        }
+    }
+}
+
+#[derive(Default)]
+struct Database {
+    posts: Vec<Post>,
+    full: bool,
+}
+
+impl beaver::Persister<Post> for Database {
+    type Error = String;
+
+    fn save(&mut self, mut post: Post) -> Result<Post, Self::Error> {
+        if self.full {
+            return Err(format!("Failed to save `{}`", post.title));
+        }
+        post.id = self.posts.len() as u64 + 100;
+        self.posts.push(post.clone());
+        Ok(post)
+    }
+}
+
+#[test]
+fn is_creates_struct_with_persister() {
+    let post_factory = PostFactory::new();
+    let mut db = Database::default();
+
+    let post = post_factory
+        .create(&mut db, |post| post.log.push("override".to_string()))
+        .unwrap();
+    let posts = post_factory.create_list(2, &mut db, |_| {}).unwrap();
+
+    assert_eq!(
+        post,
+        Post {
+            id: 100,
+            title: "post-1".to_string(),
+            log: vec![
+                "override".to_string(),
+                "after_build".to_string(),
+                "before_create".to_string(),
+                "after_create-1".to_string(),
+            ],
+        }
+    );
+    assert_eq!(db.posts[0].log.len(), 3);
+    assert_eq!(
+        posts.iter().map(|post| post.id).collect::<Vec<_>>(),
+        vec![101, 102]
+    );
+    assert_eq!(db.posts.len(), 3);
+}
+
+#[test]
+fn is_returns_persister_error() {
+    let post_factory = PostFactory::new();
+    let mut db = Database {
+        posts: vec![],
+        full: true,
+    };
+
+    assert_eq!(
+        post_factory.create_list(2, &mut db, |_| {}),
+        Err("Failed to save `post-1`".to_string())
+    );
+}
+
+#[test]
+fn is_returns_build_and_persister_errors_with_try_create() {
+    let post_factory = PostFactory::new();
+    let mut db = Database {
+        posts: vec![],
+        full: true,
+    };
+
+    match post_factory.try_create_list(2, &mut db, |_| {}) {
+        Err(beaver::CreateError::Persist(err)) => assert_eq!(err, "Failed to save `post-1`"),
+        result => panic!("unexpected result: {:?}", result),
+    }
+
+    db.full = false;
+    post_factory.set_sequence(u64::MAX);
+    match post_factory.try_create(&mut db, |_| {}) {
+        Err(beaver::CreateError::Build(beaver::Error::SequenceOverflow)) => {}
+        result => panic!("unexpected result: {:?}", result),
+    }
+    assert!(db.posts.is_empty());
+}
+
+#[test]
+fn is_creates_struct_with_in_memory_persister() {
+    let post_factory = PostFactory::new();
+    let mut posts = vec![];
+    let mut persister = beaver::HashMapPersister::new(|post: &Post| post.title.clone());
+
+    post_factory.create_list(2, &mut posts, |_| {}).unwrap();
+    post_factory.create(&mut persister, |_| {}).unwrap();
+
+    assert_eq!(posts.len(), 2);
+    assert_eq!(posts[1].title, "post-2");
+    assert_eq!(persister.records["post-3"].log.len(), 2);
+}
+
+#[test]
+fn is_not_running_create_hooks_when_building() {
+    let post_factory = PostFactory::new();
+
+    let post = post_factory.build(|_| {});
+
+    assert_eq!(post.log, vec!["after_build".to_string()]);
+}
@@ -0,0 +1,92 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Default)]
+struct Settings {
+    id: u64,
+    name: String,
+    theme: String,
+    font_size: u32,
+    notifications: bool,
+    tags: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Default)]
+struct Point(i64, i64);
+
+fn base_settings() -> Settings {
+    Settings {
+        theme: "dark".to_string(),
+        font_size: 14,
+        ..Default::default()
+    }
+}
+
+beaver::define! {
+    SettingsFactory (Settings) {
+        id -> |n| n,
+        name -> |n| format!("settings-{}", n),
+        ..Default
+    }
+}
+
+beaver::define! {
+    DarkSettingsFactory (Settings) {
+        id -> |n| n,
+        notifications -> |_| true,
+        trait large {
+            font_size -> |_| 20,
+        }
+        ..default_model(base_settings())
+    }
+}
+
+beaver::define! {
+    PointFactory (Point) clone {
+        0 -> |n| n as i64,
+        ..Default,
+    }
+}
+
+#[test]
+fn is_fills_unlisted_fields_with_default() {
+    let settings_factory = SettingsFactory::new();
+
+    let settings1 = settings_factory.build(|_| {});
+    let settings2 = settings_factory.build(|settings| settings.font_size = 12);
+    assert_eq!(
+        settings1,
+        Settings {
+            id: 1,
+            name: "settings-1".to_string(),
+            ..Default::default()
+        }
+    );
+    assert_eq!(settings2.id, 2);
+    assert_eq!(settings2.font_size, 12);
+    assert!(settings2.tags.is_empty());
+}
+
+#[test]
+fn is_fills_unlisted_fields_with_default_model() {
+    let dark_settings_factory = DarkSettingsFactory::new();
+
+    let settings1 = dark_settings_factory.build(|_| {});
+    let settings2 = dark_settings_factory.build_with_traits(&["large"], |_| {});
+    assert_eq!(
+        settings1,
+        Settings {
+            id: 1,
+            notifications: true,
+            ..base_settings()
+        }
+    );
+    assert_eq!(settings2.theme, "dark");
+    assert_eq!(settings2.font_size, 20);
+}
+
+#[test]
+fn is_fills_unlisted_positional_fields() {
+    let point_factory = PointFactory::new();
+
+    assert_eq!(point_factory.build(|_| {}), Point(1, 0));
+}
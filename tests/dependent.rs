@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct User {
+    id: u64,
+    name: String,
+    email: String,
+    admin: bool,
+}
+
+beaver::define! {
+    UserFactory (User) {
+        transient UserTransient {
+            domain: &'static str = "example.com",
+        }
+        id -> |n| n,
+        name -> |n| format!("User {}", n),
+        email -> |_, user| format!(
+            "{}@{}",
+            user.name.to_lowercase().replace(' ', "-"),
+            user.domain
+        ),
+        admin -> |_, user| *user.id == 1,
+        trait renamed {
+            name -> |n| format!("Renamed {}", n),
+            email -> |_, user| format!("{}@example.org", user.name.to_lowercase().replace(' ', "-")),
+        }
+    }
+}
+
+#[test]
+fn is_builds_struct_with_dependent_attributes() {
+    let user_factory = UserFactory::new();
+
+    let user1 = user_factory.build(|_| {});
+    let user2 = user_factory.build_with_transient(|t| t.domain = "example.net", |_| {});
+    let user3 = user_factory.build_with_traits(&["renamed"], |_| {});
+
+    assert_eq!(
+        user1,
+        User {
+            id: 1,
+            name: "User 1".to_string(),
+            email: "user-1@example.com".to_string(),
+            admin: true,
+        }
+    );
+    assert_eq!(
+        user2,
+        User {
+            id: 2,
+            name: "User 2".to_string(),
+            email: "user-2@example.net".to_string(),
+            admin: false,
+        }
+    );
+    assert_eq!(
+        user3,
+        User {
+            id: 3,
+            name: "Renamed 3".to_string(),
+            email: "renamed-3@example.org".to_string(),
+            admin: false,
+        }
+    );
+}
+
+#[test]
+fn is_evaluates_dependent_attributes_in_the_template() {
+    let user_factory = UserFactory::new();
+
+    assert_eq!(
+        user_factory.model,
+        r#"{"id":1,"name":"User 1","email":"user-1@example.com","admin":true}"#
+    );
+}
@@ -0,0 +1,133 @@
+#[cfg(feature = "random")]
+use beaver::rand::Rng;
+use beaver::Error;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+enum Event {
+    Created { id: u64, title: String },
+    Deleted { id: u64, reason: String },
+    Archived,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub enum Payment {
+    Card { amount: u64 },
+}
+
+beaver::define! {
+    EventFactory (Event) {
+        Created {
+            id -> |n| n,
+            title -> |n| format!("event-{}", n),
+        },
+        Deleted {
+            id -> |n| n,
+            reason -> |n| format!("reason-{}", n),
+        },
+        Archived {},
+    }
+}
+
+#[cfg(feature = "random")]
+beaver::define! {
+    pub PaymentFactory (Payment) {
+        Card {
+            amount -> |_, ctx| ctx.rng().gen_range(1..=100),
+        }
+    }
+}
+
+#[test]
+fn is_builds_variants_in_turn() {
+    let event_factory = EventFactory::new();
+
+    let events = event_factory.build_list(4, |_| {});
+    assert_eq!(
+        events,
+        vec![
+            Event::Created {
+                id: 1,
+                title: "event-1".to_string()
+            },
+            Event::Deleted {
+                id: 2,
+                reason: "reason-2".to_string()
+            },
+            Event::Archived,
+            Event::Created {
+                id: 4,
+                title: "event-4".to_string()
+            },
+        ]
+    );
+}
+
+#[test]
+fn is_builds_variant() {
+    let event_factory = EventFactory::new();
+
+    let event1 = event_factory.build_variant("Deleted", |_| {});
+    let event2 = event_factory.build_variant("Deleted", |event| {
+        if let Event::Deleted { reason, .. } = event {
+            *reason = "spam".to_string();
+        }
+    });
+    assert_eq!(
+        event1,
+        Event::Deleted {
+            id: 1,
+            reason: "reason-1".to_string()
+        }
+    );
+    assert_eq!(
+        event2,
+        Event::Deleted {
+            id: 2,
+            reason: "spam".to_string()
+        }
+    );
+    assert_eq!(
+        event_factory.build_variant("Archived", |_| {}),
+        Event::Archived
+    );
+}
+
+#[test]
+fn is_returns_error_for_unknown_variant() {
+    let event_factory = EventFactory::new();
+
+    match event_factory.try_build_variant("Updated", |_| {}) {
+        Err(Error::UnknownVariant(variant)) => assert_eq!(variant, "Updated"),
+        result => panic!("unexpected result: {:?}", result.map(|_| ())),
+    }
+    // The sequence isn't consumed by the unknown variant.
+    assert_eq!(
+        event_factory.build(|_| {}),
+        Event::Created {
+            id: 1,
+            title: "event-1".to_string()
+        }
+    );
+}
+
+#[test]
+fn is_uses_shortcuts_for_enum() {
+    assert_eq!(
+        EventFactory::build(2),
+        Event::Deleted {
+            id: 2,
+            reason: "reason-2".to_string()
+        }
+    );
+    assert_eq!(EventFactory::build_list(2, 3).len(), 2);
+}
+
+#[cfg(feature = "random")]
+#[test]
+fn is_generates_random_values_in_variant() {
+    let payment_factory = PaymentFactory::new();
+
+    let Payment::Card { amount } = payment_factory.build_variant("Card", |_| {});
+    assert!((1..=100).contains(&amount));
+}
@@ -0,0 +1,89 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Post {
+    id: u64,
+    title: String,
+    file: File,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct File {
+    id: u64,
+    path: String,
+}
+
+beaver::define! {
+    PostFactory (Post) {
+        id -> |n| n,
+        title -> |n| format!("post-{}", n),
+        file -> |n| File {
+            id: n,
+            path: format!("path/to/file-{}", n),
+        },
+        trait untitled {
+            title -> |_| "".to_string(),
+        }
+    }
+}
+
+#[test]
+fn is_builds_struct_without_errors() {
+    let post_factory = PostFactory::new();
+
+    let post = post_factory.try_build(|_| {}).unwrap();
+    let posts = post_factory.try_build_list(2, |_| {}).unwrap();
+
+    assert_eq!(post.id, 1);
+    assert_eq!(
+        posts.iter().map(|post| post.id).collect::<Vec<_>>(),
+        vec![2, 3]
+    );
+}
+
+#[test]
+fn is_returns_deserialization_error_with_path() {
+    let mut post_factory = PostFactory::new();
+    post_factory.model = r#"{"id":1,"title":"post","file":{"id":1,"path":null}}"#.to_string();
+
+    match post_factory.try_build(|_| {}) {
+        Err(beaver::Error::Deserialization { json, path, .. }) => {
+            assert_eq!(json, post_factory.model);
+            assert_eq!(path, "file.path");
+        }
+        result => panic!("unexpected result: {:?}", result),
+    }
+    assert!(post_factory.try_build_list(2, |_| {}).is_err());
+}
+
+#[test]
+fn is_returns_error_with_undefined_trait() {
+    let post_factory = PostFactory::new();
+
+    assert!(post_factory
+        .try_build_with_traits(&["untitled"], |_| {})
+        .is_ok());
+    assert!(matches!(
+        post_factory.try_build_with_traits(&["published"], |_| {}),
+        Err(beaver::Error::UnknownTrait(name)) if name == "published"
+    ));
+}
+
+#[test]
+fn is_returns_error_with_overflowed_sequence() {
+    let post_factory = PostFactory::new();
+    post_factory.sequence.set(u64::MAX);
+
+    assert!(matches!(
+        post_factory.try_build(|_| {}),
+        Err(beaver::Error::SequenceOverflow)
+    ));
+}
+
+#[test]
+#[should_panic(expected = "Failed to deserialize a model at `file.path`")]
+fn is_panics_with_deserialization_error() {
+    let mut post_factory = PostFactory::new();
+    post_factory.model = r#"{"id":1,"title":"post","file":{"id":1,"path":null}}"#.to_string();
+    post_factory.build(|_| {});
+}
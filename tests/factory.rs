@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug)]
 struct Post {
-    id: u16,
+    id: u64,
     title: String,
     approved: bool,
     file: File,
@@ -13,13 +13,13 @@ struct Post {
 
 #[derive(Serialize, Deserialize, Debug)]
 struct File {
-    id: u16,
+    id: u64,
     path: String,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
 struct Tag {
-    id: u16,
+    id: u64,
     name: String,
 }
 
@@ -53,7 +53,7 @@ beaver::define! {
         approved -> |_| false,
         file -> |n| FileFactory::build(n),
         tags -> |n| TagFactory::build_list(3, n),
-        created_at -> |_| NaiveDate::from_ymd(2020, 1, 1).and_hms(0, 0, 0),
+        created_at -> |_| NaiveDate::from_ymd_opt(2020, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap(),
     }
 }
 
@@ -109,7 +109,10 @@ fn is_builds_struct() {
                     name: "tag-3".to_string()
                 }
             ],
-            created_at: NaiveDate::from_ymd(2020, 1, 1).and_hms(0, 0, 0)
+            created_at: NaiveDate::from_ymd_opt(2020, 1, 1)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap()
         }
     );
     assert_eq!(
@@ -136,7 +139,10 @@ fn is_builds_struct() {
                     name: "tag-6".to_string()
                 }
             ],
-            created_at: NaiveDate::from_ymd(2020, 1, 1).and_hms(0, 0, 0)
+            created_at: NaiveDate::from_ymd_opt(2020, 1, 1)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap()
         }
     );
     assert_eq!(
@@ -163,7 +169,10 @@ fn is_builds_struct() {
                     name: "tag-9".to_string()
                 }
             ],
-            created_at: NaiveDate::from_ymd(2020, 1, 1).and_hms(0, 0, 0)
+            created_at: NaiveDate::from_ymd_opt(2020, 1, 1)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap()
         }
     );
     // post with id 3 doesn't exist
@@ -192,7 +201,10 @@ fn is_builds_struct() {
                         name: "tag-12".to_string()
                     }
                 ],
-                created_at: NaiveDate::from_ymd(2020, 1, 1).and_hms(0, 0, 0)
+                created_at: NaiveDate::from_ymd_opt(2020, 1, 1)
+                    .unwrap()
+                    .and_hms_opt(0, 0, 0)
+                    .unwrap()
             },
             Post {
                 id: 5,
@@ -216,8 +228,39 @@ fn is_builds_struct() {
                         name: "tag-15".to_string()
                     }
                 ],
-                created_at: NaiveDate::from_ymd(2020, 1, 1).and_hms(0, 0, 0)
+                created_at: NaiveDate::from_ymd_opt(2020, 1, 1)
+                    .unwrap()
+                    .and_hms_opt(0, 0, 0)
+                    .unwrap()
             }
         ]
     )
 }
+
+#[test]
+fn is_builds_struct_beyond_u16() {
+    let post_factory = PostFactory::new();
+    post_factory.sequence.set(70_000);
+
+    let post = post_factory.build(|_| {});
+    let tags = TagFactory::build_list(3, 70_000);
+
+    assert_eq!(post.id, 70_000);
+    assert_eq!(post.title, "post-70000");
+    assert_eq!(
+        tags.iter().map(|tag| tag.id).collect::<Vec<_>>(),
+        vec![209_998, 209_999, 210_000]
+    );
+}
+
+#[test]
+#[should_panic(expected = "Sequence number overflowed")]
+fn is_panics_with_overflowed_sequence() {
+    TagFactory::build_list(2, u64::MAX);
+}
+
+#[test]
+#[should_panic(expected = "Sequence number is 0")]
+fn is_panics_with_zero_sequence() {
+    TagFactory::build_list(2, 0);
+}
@@ -0,0 +1,98 @@
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Page<T> {
+    number: u64,
+    items: Vec<T>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Envelope<P> {
+    id: u64,
+    payload: P,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Default)]
+struct Payload {
+    body: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Wrapper<'a> {
+    id: u64,
+    name: &'a str,
+}
+
+beaver::define! {
+    PageFactory<T: Serialize + DeserializeOwned> (Page<T>) {
+        number -> |n| n,
+        items -> |_| vec![],
+    }
+}
+
+beaver::define! {
+    DefaultPageFactory<T> (Page<T>) where T: Serialize + DeserializeOwned + Default {
+        number -> |n| n,
+        items -> |_| vec![T::default()],
+    }
+}
+
+beaver::define! {
+    EnvelopeFactory (Envelope<Payload>) {
+        id -> |n| n,
+        payload -> |n| Payload {
+            body: format!("payload-{}", n),
+        },
+    }
+}
+
+beaver::define! {
+    WrapperFactory<'a> (Wrapper<'a>) clone {
+        id -> |n| n,
+        name -> |_| "wrapper",
+    }
+}
+
+beaver::define! {
+    LastPageFactory<T> (Page<T>) : PageFactory<T> where T: Serialize + DeserializeOwned + 'static {
+        number -> |_| 100,
+    }
+}
+
+#[test]
+fn is_builds_generic_struct() {
+    let page_factory = PageFactory::<String>::new();
+    let page = page_factory.build(|page| page.items.push("item".to_string()));
+    assert_eq!(
+        page,
+        Page {
+            number: 1,
+            items: vec!["item".to_string()],
+        }
+    );
+
+    let pages: Vec<Page<u64>> = PageFactory::build_list(2, 10);
+    assert_eq!(pages[1].number, 20);
+
+    let page = DefaultPageFactory::<Payload>::build(1);
+    assert_eq!(page.items, vec![Payload::default()]);
+
+    let page = LastPageFactory::<u64>::new().build(|_| {});
+    assert_eq!(page.number, 100);
+}
+
+#[test]
+fn is_builds_struct_with_generic_arguments() {
+    let envelope = EnvelopeFactory::build(1);
+    assert_eq!(envelope.payload.body, "payload-1");
+
+    let wrapper = WrapperFactory::new().build(|_| {});
+    assert_eq!(
+        wrapper,
+        Wrapper {
+            id: 1,
+            name: "wrapper",
+        }
+    );
+}
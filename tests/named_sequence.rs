@@ -0,0 +1,109 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::thread;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct User {
+    id: u64,
+    email: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Admin {
+    id: u64,
+    email: String,
+    code: u64,
+}
+
+beaver::sequence!(email, |n| format!("person{}@example.com", n));
+beaver::sequence!(code: u64, |n| n * 10);
+
+beaver::define! {
+    UserFactory (User) {
+        id -> |n| n,
+        email -> |_| beaver::next(email),
+    }
+}
+
+beaver::define! {
+    AdminFactory (Admin) {
+        id -> |n| n,
+        email -> |_| beaver::next(email),
+        code -> |_| beaver::next(code),
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Team {
+    id: u64,
+    owner: Member,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Member {
+    id: u64,
+    email: String,
+}
+
+beaver::sequence!(member_email, |n| format!("member{}@example.com", n));
+
+beaver::define! {
+    MemberFactory (Member) {
+        id -> |n| n,
+        email -> |_| beaver::next(member_email),
+    }
+}
+
+beaver::define! {
+    TeamFactory (Team) {
+        id -> |n| n,
+        owner -> |n| MemberFactory::build(n),
+    }
+}
+
+#[test]
+fn is_generates_values_only_on_builds() {
+    let member_factory = MemberFactory::new();
+    MemberFactory::new_sync();
+    let team_factory = TeamFactory::new();
+
+    let member = member_factory.build(|_| {});
+    let team = team_factory.build(|_| {});
+
+    assert_eq!(member.email, "member1@example.com");
+    assert_eq!(team.owner.email, "member2@example.com");
+    assert_eq!(beaver::next(member_email), "member3@example.com");
+}
+
+#[test]
+fn is_generates_unique_values_across_factories() {
+    let user_factory = UserFactory::new();
+    let admin_factory = AdminFactory::new();
+
+    let users = user_factory.build_list(3, |_| {});
+    let admins = admin_factory.build_list(3, |_| {});
+
+    let emails: HashSet<_> = users
+        .iter()
+        .map(|user| user.email.clone())
+        .chain(admins.iter().map(|admin| admin.email.clone()))
+        .collect();
+    assert_eq!(emails.len(), 6);
+    assert!(admins[0].code < admins[1].code);
+    assert_eq!(admins[1].code % 10, 0);
+}
+
+#[test]
+fn is_generates_unique_values_across_threads() {
+    let values: HashSet<_> = thread::scope(|s| {
+        let handles: Vec<_> = (0..4)
+            .map(|_| s.spawn(|| (0..25).map(|_| beaver::next(code)).collect::<Vec<_>>()))
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect()
+    });
+
+    assert_eq!(values.len(), 100);
+}
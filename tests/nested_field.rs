@@ -0,0 +1,117 @@
+use beaver::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct File {
+    id: u64,
+    path: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Address {
+    city: String,
+    location: (f64, f64),
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct User {
+    id: u64,
+    name: String,
+    file: File,
+    address: Address,
+}
+
+beaver::define! {
+    FileFactory (File) {
+        id -> |n| n,
+        path -> |n| format!("path/to/file-{}", n),
+    }
+}
+
+beaver::define! {
+    UserFactory (User) {
+        id -> |n| n,
+        name -> |n| format!("user-{}", n),
+        file -> |n| FileFactory::build(n),
+        file.path -> |_, ctx| format!("{}.png", ctx.name),
+        address -> |_| Address {
+            city: "Tokyo".to_string(),
+            location: (0.0, 0.0),
+        },
+        trait osaka {
+            address.city -> |_| "Osaka".to_string(),
+            address.location.0 -> |_| 34.7,
+        }
+    }
+}
+
+beaver::define! {
+    KyotoUserFactory (User) : UserFactory {
+        address.city -> |_| "Kyoto".to_string(),
+    }
+}
+
+#[test]
+fn is_overrides_nested_fields() {
+    let user_factory = UserFactory::new();
+
+    let user1 = user_factory.build(|_| {});
+    let user2 = user_factory.build_with_traits(&["osaka"], |_| {});
+    assert_eq!(
+        user1.file,
+        File {
+            id: 1,
+            path: "user-1.png".to_string()
+        }
+    );
+    assert_eq!(user1.address.city, "Tokyo");
+    assert_eq!(user2.file.path, "user-2.png");
+    assert_eq!(
+        user2.address,
+        Address {
+            city: "Osaka".to_string(),
+            location: (34.7, 0.0)
+        }
+    );
+}
+
+#[test]
+fn is_overrides_nested_fields_in_child_factory() {
+    let kyoto_user_factory = KyotoUserFactory::new();
+
+    let user = kyoto_user_factory.build(|_| {});
+    assert_eq!(user.address.city, "Kyoto");
+    assert_eq!(user.file.path, "user-1.png");
+}
+
+#[test]
+fn is_overrides_fields_by_path_at_runtime() {
+    let user_factory = UserFactory::new();
+
+    let user = user_factory.build_with_overrides(
+        &[
+            ("file.path", json!("avatar.png")),
+            ("address.location.1", json!(135.5)),
+        ],
+        |user| user.name = "beaver".to_string(),
+    );
+    assert_eq!(user.id, 1);
+    assert_eq!(user.name, "beaver");
+    assert_eq!(user.file.path, "avatar.png");
+    assert_eq!(user.address.location, (0.0, 135.5));
+}
+
+#[test]
+fn is_returns_error_for_invalid_override() {
+    let user_factory = UserFactory::new();
+
+    match user_factory.try_build_with_overrides(&[("file.name", json!("avatar.png"))], |_| {}) {
+        Err(Error::UnknownField(path)) => assert_eq!(path, "file.name"),
+        result => panic!("unexpected result: {:?}", result),
+    }
+    match user_factory.try_build_with_overrides(&[("file.id", json!("one"))], |_| {}) {
+        Err(Error::Deserialization { path, .. }) => assert_eq!(path, "file.id"),
+        result => panic!("unexpected result: {:?}", result),
+    }
+}
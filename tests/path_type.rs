@@ -0,0 +1,115 @@
+mod models {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    pub struct Post {
+        pub id: u64,
+        pub title: String,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    pub struct Page<T> {
+        pub number: u64,
+        pub items: Vec<T>,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    pub struct UserId(pub u64);
+
+    pub mod events {
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        pub enum Event {
+            Created { id: u64 },
+            Deleted { id: u64 },
+        }
+    }
+}
+
+type Draft = models::Post;
+
+mod support {
+    beaver::define! {
+        pub PostFactory (crate::models::Post) {
+            id -> |n| n,
+            title -> |n| format!("post-{}", n),
+        }
+    }
+
+    beaver::define! {
+        pub PageFactory (crate::models::Page<crate::models::Post>) {
+            number -> |n| n,
+            items -> |n| PostFactory::build_list(2, n),
+        }
+    }
+
+    beaver::define! {
+        pub UserIdFactory (crate::models::UserId) -> |n| n
+    }
+
+    beaver::define! {
+        pub EventFactory (crate::models::events::Event) {
+            Created {
+                id -> |n| n,
+            },
+            Deleted {
+                id -> |n| n,
+            },
+        }
+    }
+
+    beaver::define! {
+        pub DraftFactory (crate::Draft) : PostFactory {
+            title -> |n| format!("draft-{}", n),
+        }
+    }
+}
+
+beaver::define! {
+    AliasFactory (Draft) {
+        id -> |n| n,
+        title -> |n| format!("alias-{}", n),
+    }
+}
+
+use models::events::Event;
+use models::{Post, UserId};
+use support::{DraftFactory, EventFactory, PageFactory, PostFactory, UserIdFactory};
+
+#[test]
+fn is_builds_struct_by_path() {
+    let post = PostFactory::new().build(|_| {});
+    assert_eq!(
+        post,
+        Post {
+            id: 1,
+            title: "post-1".to_string()
+        }
+    );
+
+    let page = PageFactory::new().build(|_| {});
+    assert_eq!(page.items.len(), 2);
+    assert_eq!(page.items[0].title, "post-1");
+
+    assert_eq!(UserIdFactory::new().build(|_| {}), UserId(1));
+}
+
+#[test]
+fn is_builds_enum_by_path() {
+    let event_factory = EventFactory::new();
+
+    assert_eq!(event_factory.build(|_| {}), Event::Created { id: 1 });
+    assert_eq!(
+        event_factory.build_variant("Deleted", |_| {}),
+        Event::Deleted { id: 2 }
+    );
+}
+
+#[test]
+fn is_builds_struct_by_alias() {
+    let alias = AliasFactory::new().build(|_| {});
+    let draft = DraftFactory::new().build(|_| {});
+    assert_eq!(alias.title, "alias-1");
+    assert_eq!(draft.title, "draft-1");
+}
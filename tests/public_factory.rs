@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Post {
-    id: u16,
+    id: u64,
     title: String,
     approved: bool,
     file: File,
@@ -13,13 +13,13 @@ pub struct Post {
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct File {
-    id: u16,
+    id: u64,
     path: String,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Tag {
-    id: u16,
+    id: u64,
     name: String,
 }
 
@@ -57,7 +57,7 @@ mod factory {
             approved -> |_| false,
             file -> |n| FileFactory::build(n),
             tags -> |n| TagFactory::build_list(3, n),
-            created_at -> |_| NaiveDate::from_ymd(2020, 1, 1).and_hms(0, 0, 0),
+            created_at -> |_| NaiveDate::from_ymd_opt(2020, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap(),
         }
     }
 
@@ -116,7 +116,10 @@ fn is_builds_struct() {
                     name: "tag-3".to_string()
                 }
             ],
-            created_at: NaiveDate::from_ymd(2020, 1, 1).and_hms(0, 0, 0)
+            created_at: NaiveDate::from_ymd_opt(2020, 1, 1)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap()
         }
     );
     assert_eq!(
@@ -143,7 +146,10 @@ fn is_builds_struct() {
                     name: "tag-6".to_string()
                 }
             ],
-            created_at: NaiveDate::from_ymd(2020, 1, 1).and_hms(0, 0, 0)
+            created_at: NaiveDate::from_ymd_opt(2020, 1, 1)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap()
         }
     );
     assert_eq!(
@@ -170,7 +176,10 @@ fn is_builds_struct() {
                     name: "tag-9".to_string()
                 }
             ],
-            created_at: NaiveDate::from_ymd(2020, 1, 1).and_hms(0, 0, 0)
+            created_at: NaiveDate::from_ymd_opt(2020, 1, 1)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap()
         }
     );
     // post with id 3 doesn't exist
@@ -199,7 +208,10 @@ fn is_builds_struct() {
                         name: "tag-12".to_string()
                     }
                 ],
-                created_at: NaiveDate::from_ymd(2020, 1, 1).and_hms(0, 0, 0)
+                created_at: NaiveDate::from_ymd_opt(2020, 1, 1)
+                    .unwrap()
+                    .and_hms_opt(0, 0, 0)
+                    .unwrap()
             },
             Post {
                 id: 5,
@@ -223,7 +235,10 @@ fn is_builds_struct() {
                         name: "tag-15".to_string()
                     }
                 ],
-                created_at: NaiveDate::from_ymd(2020, 1, 1).and_hms(0, 0, 0)
+                created_at: NaiveDate::from_ymd_opt(2020, 1, 1)
+                    .unwrap()
+                    .and_hms_opt(0, 0, 0)
+                    .unwrap()
             }
         ]
     )
@@ -0,0 +1,64 @@
+#![cfg(feature = "random")]
+
+use beaver::rand::Rng;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Product {
+    id: u64,
+    price: u64,
+    sizes: Vec<u8>,
+    discount: u64,
+}
+
+beaver::define! {
+    ProductFactory (Product) {
+        id -> |n| n,
+        price -> |_, ctx| ctx.rng().gen_range(1..100),
+        sizes -> |_, ctx| (0..3).map(|_| ctx.rng().gen()).collect(),
+        discount -> |_| 0,
+        trait discounted {
+            discount -> |_, product| product.price / 2,
+        }
+    }
+}
+
+beaver::define! {
+    ExpensiveProductFactory (Product) : ProductFactory {
+        price -> |_, ctx| ctx.rng().gen_range(1000..2000),
+    }
+}
+
+// The global seed is shared by the tests in this file, so it is checked in one test.
+#[test]
+fn is_generates_reproducible_random_values() {
+    beaver::set_seed(42);
+    let product_factory = ProductFactory::new();
+    let products1 = product_factory.build_list(10, |_| {});
+    product_factory.reset_sequence();
+    let products2 = product_factory.build_list(10, |_| {});
+    assert_eq!(products1, products2);
+    assert!(products1
+        .iter()
+        .all(|product| (1..100).contains(&product.price)));
+    assert!(products1
+        .windows(2)
+        .any(|pair| pair[0].price != pair[1].price));
+    // Values are drawn from one generator per field, so they differ from each other.
+    assert!(products1
+        .iter()
+        .any(|product| product.sizes[0] != product.sizes[1]));
+
+    // A struct gets the same values regardless of how many structs are built before it.
+    assert_eq!(ProductFactory::build(5), products1[4]);
+    product_factory.set_sequence(3);
+    let product = product_factory.build_with_traits(&["discounted"], |_| {});
+    assert_eq!(product.price, products1[2].price);
+    assert_eq!(product.discount, product.price / 2);
+
+    let expensive_product = ExpensiveProductFactory::build(1);
+    assert!((1000..2000).contains(&expensive_product.price));
+
+    beaver::set_seed(43);
+    assert_ne!(ProductFactory::build_list(10, 1), products1);
+}
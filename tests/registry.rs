@@ -0,0 +1,108 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Post {
+    id: u64,
+    title: String,
+    user: User,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct User {
+    id: u64,
+    name: String,
+}
+
+beaver::define! {
+    PostFactory (Post) {
+        id -> |n| n,
+        title -> |n| format!("post-{}", n),
+        // registered factories can build other registered factories.
+        user -> |_| beaver::build("registry_user", |_| {}),
+        trait draft {
+            title -> |n| format!("draft-{}", n),
+        }
+    }
+}
+
+beaver::define! {
+    UserFactory (User) {
+        id -> |n| n,
+        name -> |n| format!("user-{}", n),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Tag {
+    id: u64,
+}
+
+beaver::define! {
+    TagFactory (Tag) clone {
+        id -> |n| n,
+    }
+}
+
+fn setup() {
+    beaver::register("registry_user", UserFactory::new_sync());
+    beaver::register("registry_post", PostFactory::new_sync());
+}
+
+#[test]
+fn is_builds_struct_by_name() {
+    setup();
+
+    let post = beaver::build::<Post>("registry_post", |post| post.title = "foo".to_string());
+    let value = beaver::build_value("registry_user");
+
+    assert_eq!(post.title, "foo");
+    assert!(post.user.name.starts_with("user-"));
+    assert_eq!(value["name"], json!(format!("user-{}", value["id"])));
+    assert!(beaver::registered().contains(&"registry_post"));
+}
+
+#[test]
+fn is_returns_error_with_unknown_factory() {
+    setup();
+
+    match beaver::try_build_value("registry_comment") {
+        Err(err @ beaver::Error::UnknownFactory { .. }) => {
+            let message = err.to_string();
+            assert!(message.starts_with("Unexpected factory `registry_comment`."));
+            assert!(message.contains("`registry_post`"));
+            assert!(message.contains("`registry_user`"));
+        }
+        result => panic!("unexpected result: {:?}", result),
+    }
+}
+
+#[test]
+fn is_returns_error_with_unexpected_type() {
+    setup();
+
+    assert!(matches!(
+        beaver::try_build::<User>("registry_post", |_| {}),
+        Err(beaver::Error::FactoryType { expected, .. }) if expected.ends_with("User")
+    ));
+}
+
+#[test]
+#[should_panic(expected = "Unexpected factory `registry_tag`")]
+fn is_panics_with_unknown_factory() {
+    beaver::build_value("registry_tag");
+}
+
+#[test]
+fn is_builds_struct_from_cloned_template_by_name() {
+    beaver::register("registry_cloned_tag", TagFactory::new_sync());
+
+    assert_eq!(
+        beaver::build::<Tag>("registry_cloned_tag", |_| {}),
+        Tag { id: 1 }
+    );
+    assert!(matches!(
+        beaver::try_build_value("registry_cloned_tag"),
+        Err(beaver::Error::NotSerializable(name)) if name == "registry_cloned_tag"
+    ));
+}
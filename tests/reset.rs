@@ -0,0 +1,84 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Post {
+    id: u64,
+    title: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Article {
+    id: u64,
+    slug: String,
+}
+
+beaver::sequence!(slug, |n| format!("slug-{}", n));
+
+beaver::define! {
+    PostFactory (Post) {
+        id -> |n| n,
+        title -> |n| format!("post-{}", n),
+    }
+}
+
+beaver::define! {
+    ArticleFactory (Article) {
+        id -> |n| n,
+        slug -> |_| beaver::next(slug),
+    }
+}
+
+#[test]
+fn is_resets_sequence_of_factory() {
+    let post_factory = PostFactory::new();
+
+    post_factory.build_list(2, |_| {});
+    post_factory.reset_sequence();
+    let post1 = post_factory.build(|_| {});
+    post_factory.set_sequence(10);
+    let post2 = post_factory.build(|_| {});
+
+    assert_eq!(post1.title, "post-1");
+    assert_eq!(post2.title, "post-10");
+    assert_eq!(post_factory.sequence.get(), 11);
+}
+
+// Global counters are shared by the tests in this file, so they are checked in one test.
+#[test]
+fn is_resets_and_restores_global_sequences() {
+    beaver::register("reset_post", PostFactory::new_sync());
+
+    let snapshot = beaver::snapshot();
+    assert_eq!(beaver::next(slug), "slug-1");
+    assert_eq!(beaver::build::<Post>("reset_post", |_| {}).id, 1);
+
+    let middle = beaver::snapshot();
+    assert_eq!(beaver::next(slug), "slug-2");
+    assert_eq!(beaver::build::<Post>("reset_post", |_| {}).id, 2);
+
+    beaver::restore(&middle);
+    assert_eq!(beaver::next(slug), "slug-2");
+    assert_eq!(beaver::build::<Post>("reset_post", |_| {}).id, 2);
+
+    beaver::restore(&snapshot);
+    assert_eq!(beaver::next(slug), "slug-1");
+    assert_eq!(beaver::build::<Post>("reset_post", |_| {}).id, 1);
+
+    beaver::next(slug);
+    beaver::reset_all();
+    assert_eq!(beaver::next(slug), "slug-1");
+    assert_eq!(beaver::build::<Post>("reset_post", |_| {}).id, 1);
+
+    // A factory which uses a named sequence gets its first value after a reset.
+    let article_factory = ArticleFactory::new();
+    article_factory.build_list(2, |_| {});
+    beaver::reset_all();
+    let article = ArticleFactory::new().build(|_| {});
+    assert_eq!(
+        article,
+        Article {
+            id: 1,
+            slug: "slug-1".to_string(),
+        }
+    );
+}
@@ -0,0 +1,26 @@
+#![cfg(feature = "random")]
+
+use std::env;
+use std::process::Command;
+
+// Marks the process which the test spawns to read the seed.
+const CHILD_ENV: &str = "BEAVER_SEED_TEST_CHILD";
+
+#[test]
+fn is_reads_seed_from_environment_variable() {
+    if env::var_os(CHILD_ENV).is_some() {
+        assert_eq!(beaver::seed(), 12345);
+        return;
+    }
+
+    // The seed is read once per process, so a new process reads it with the variable set
+    // regardless of other tests.
+    let status = Command::new(env::current_exe().unwrap())
+        .args(["is_reads_seed_from_environment_variable", "--exact"])
+        .env(CHILD_ENV, "1")
+        .env(beaver::SEED_ENV, "12345")
+        .status()
+        .unwrap();
+
+    assert!(status.success());
+}
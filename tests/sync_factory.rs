@@ -0,0 +1,56 @@
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+use std::thread;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Post {
+    id: u64,
+    title: String,
+}
+
+beaver::define! {
+    PostFactory (Post) {
+        id -> |n| n,
+        title -> |n| format!("post-{}", n),
+    }
+}
+
+lazy_static! {
+    static ref POST_FACTORY: beaver::SyncFactory<'static, Post> = PostFactory::new_sync();
+}
+
+#[test]
+fn is_builds_unique_structs_across_threads() {
+    let post_factory = PostFactory::new_sync();
+
+    let mut ids = thread::scope(|s| {
+        let handles: Vec<_> = (0..4)
+            .map(|_| s.spawn(|| post_factory.build_list(25, |_| {})))
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .map(|post| {
+                assert_eq!(post.title, format!("post-{}", post.id));
+                post.id
+            })
+            .collect::<Vec<_>>()
+    });
+    ids.sort_unstable();
+
+    assert_eq!(ids, (1..=100).collect::<Vec<_>>());
+}
+
+#[test]
+fn is_builds_structs_from_static_factory() {
+    let handles: Vec<_> = (0..4)
+        .map(|_| thread::spawn(|| POST_FACTORY.build(|_| {}).id))
+        .collect();
+    let mut ids: Vec<_> = handles
+        .into_iter()
+        .map(|handle| handle.join().unwrap())
+        .collect();
+    ids.sort_unstable();
+
+    assert_eq!(ids, vec![1, 2, 3, 4]);
+}
@@ -0,0 +1,82 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Post {
+    id: u64,
+    title: String,
+    approved: bool,
+}
+
+beaver::define! {
+    PostFactory (Post) {
+        id -> |n| n,
+        title -> |n| format!("post-{}", n),
+        approved -> |_| false,
+        trait approved {
+            approved -> |_| true,
+        }
+        trait draft {
+            title -> |n| format!("draft-{}", n),
+            approved -> |_| false,
+        }
+    }
+}
+
+#[test]
+fn is_builds_struct_with_traits() {
+    let post_factory = PostFactory::new();
+
+    let post1 = post_factory.build_with_traits(&["approved"], |_| {});
+    let post2 = post_factory.build_with_traits(&["approved", "draft"], |_| {});
+    let post3 = post_factory.build_with_traits(&["draft", "approved"], |post| {
+        post.title = "foo".to_string()
+    });
+    let posts = post_factory.build_list_with_traits(2, &["draft"], |_| {});
+
+    assert_eq!(
+        post1,
+        Post {
+            id: 1,
+            title: "post-1".to_string(),
+            approved: true,
+        }
+    );
+    assert_eq!(
+        post2,
+        Post {
+            id: 2,
+            title: "draft-2".to_string(),
+            approved: false,
+        }
+    );
+    assert_eq!(
+        post3,
+        Post {
+            id: 3,
+            title: "foo".to_string(),
+            approved: true,
+        }
+    );
+    assert_eq!(
+        posts,
+        vec![
+            Post {
+                id: 4,
+                title: "draft-4".to_string(),
+                approved: false,
+            },
+            Post {
+                id: 5,
+                title: "draft-5".to_string(),
+                approved: false,
+            }
+        ]
+    );
+}
+
+#[test]
+#[should_panic(expected = "Unexpected trait `published`")]
+fn is_panics_with_undefined_trait() {
+    let post_factory = PostFactory::new();
+    post_factory.build_with_traits(&["published"], |_| {});
+}
@@ -0,0 +1,101 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Post {
+    id: u64,
+    title: String,
+    tags: Vec<Tag>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Tag {
+    id: u64,
+    name: String,
+}
+
+beaver::define! {
+    PostFactory (Post) {
+        transient PostTransient {
+            tags_count: u64 = 1,
+            upcased: bool = false,
+        }
+        id -> |n| n,
+        title -> |n, t| {
+            let title = format!("post-{}", n);
+            if t.upcased {
+                title.to_uppercase()
+            } else {
+                title
+            }
+        },
+        tags -> |n, t| TagFactory::build_list(t.tags_count, n),
+        trait shouting {
+            title -> |n, _| format!("POST-{}!", n),
+        }
+    }
+}
+
+beaver::define! {
+    TagFactory (Tag) {
+        id -> |n| n,
+        name -> |n| format!("tag-{}", n),
+    }
+}
+
+#[test]
+fn is_builds_struct_with_transient_attributes() {
+    let post_factory = PostFactory::new();
+
+    let post1 = post_factory.build(|_| {});
+    let post2 = post_factory.build_with_transient(
+        |t| {
+            t.tags_count = 2;
+            t.upcased = true;
+        },
+        |_| {},
+    );
+    let post3 = post_factory.build_with_traits(&["shouting"], |_| {});
+    let posts = post_factory.build_list_with_transient(2, |t| t.tags_count = 0, |_| {});
+
+    assert_eq!(
+        post1,
+        Post {
+            id: 1,
+            title: "post-1".to_string(),
+            tags: vec![Tag {
+                id: 1,
+                name: "tag-1".to_string()
+            }],
+        }
+    );
+    assert_eq!(
+        post2,
+        Post {
+            id: 2,
+            title: "POST-2".to_string(),
+            tags: vec![
+                Tag {
+                    id: 3,
+                    name: "tag-3".to_string()
+                },
+                Tag {
+                    id: 4,
+                    name: "tag-4".to_string()
+                }
+            ],
+        }
+    );
+    assert_eq!(post3.title, "POST-3!");
+    assert_eq!(posts[0].tags, vec![]);
+    assert_eq!(posts[1].tags, vec![]);
+}
+
+#[test]
+fn is_not_serializing_transient_attributes() {
+    let post_factory = PostFactory::new();
+
+    assert_eq!(
+        post_factory.model,
+        r#"{"id":1,"title":"post-1","tags":[{"id":1,"name":"tag-1"}]}"#
+    );
+}
@@ -0,0 +1,74 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Copy)]
+struct UserId(u64);
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Point(i64, i64, String);
+
+#[derive(Clone, Debug, PartialEq)]
+struct Score(u32);
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct User {
+    id: UserId,
+    name: String,
+}
+
+beaver::define! {
+    UserIdFactory (UserId) -> |n| n
+}
+
+beaver::define! {
+    PointFactory (Point) {
+        0 -> |n| n as i64,
+        1 -> |n| -(n as i64),
+        2 -> |n| format!("point-{}", n),
+    }
+}
+
+beaver::define! {
+    ScoreFactory (Score) clone -> |n, _| (n * 10) as u32
+}
+
+beaver::define! {
+    UserFactory (User) {
+        id -> |n| UserIdFactory::build(n),
+        name -> |n| format!("user-{}", n),
+    }
+}
+
+#[test]
+fn is_builds_tuple_struct() {
+    let point_factory = PointFactory::new();
+
+    let point1 = point_factory.build(|_| {});
+    let point2 = point_factory.build(|point| point.2 = "origin".to_string());
+    assert_eq!(point1, Point(1, -1, "point-1".to_string()));
+    assert_eq!(point2, Point(2, -2, "origin".to_string()));
+}
+
+#[test]
+fn is_builds_newtype() {
+    let user_id_factory = UserIdFactory::new();
+
+    assert_eq!(user_id_factory.build(|_| {}), UserId(1));
+    assert_eq!(user_id_factory.build(|id| id.0 = 10), UserId(10));
+    assert_eq!(UserIdFactory::build_list(2, 2), vec![UserId(3), UserId(4)]);
+}
+
+#[test]
+fn is_builds_newtype_with_context() {
+    let score_factory = ScoreFactory::new();
+
+    assert_eq!(score_factory.build(|_| {}), Score(10));
+}
+
+#[test]
+fn is_nests_newtype_in_other_factory() {
+    let user_factory = UserFactory::new();
+
+    let user = user_factory.build(|_| {});
+    assert_eq!(user.id, UserId(1));
+    assert_eq!(user.name, "user-1");
+}